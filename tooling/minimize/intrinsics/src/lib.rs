@@ -18,21 +18,55 @@ pub fn eprint(t: impl Display) {
     eprintln!("{t}");
 }
 
+pub fn write(fd: i32, bytes: &[u8]) {
+    use std::io::Write;
+    match fd {
+        1 => std::io::stdout().write_all(bytes).unwrap(),
+        2 => std::io::stderr().write_all(bytes).unwrap(),
+        _ => panic!("unknown file descriptor {fd}"),
+    }
+}
+
+pub fn panic(msg: &str) -> ! {
+    panic!("{msg}");
+}
+
 pub fn exit() {
     std::process::exit(0);
 }
 
+pub fn exit_code(code: i32) {
+    std::process::exit(code);
+}
+
 pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
     let layout = Layout::from_size_align(size, align).unwrap();
     System.allocate(layout).unwrap().as_ptr() as *mut u8
 }
 
+pub unsafe fn allocate_zeroed(size: usize, align: usize) -> *mut u8 {
+    let layout = Layout::from_size_align(size, align).unwrap();
+    System.allocate_zeroed(layout).unwrap().as_ptr() as *mut u8
+}
+
 pub unsafe fn deallocate(ptr: *mut u8, size: usize, align: usize) {
     let ptr = NonNull::new(ptr).unwrap();
     let layout = Layout::from_size_align(size, align).unwrap();
     unsafe { System.deallocate(ptr, layout); }
 }
 
+pub unsafe fn reallocate(ptr: *mut u8, old_size: usize, align: usize, new_size: usize) -> *mut u8 {
+    let ptr = NonNull::new(ptr).unwrap();
+    let old_layout = Layout::from_size_align(old_size, align).unwrap();
+    let new_layout = Layout::from_size_align(new_size, align).unwrap();
+    let new_ptr = if new_size >= old_size {
+        unsafe { System.grow(ptr, old_layout, new_layout) }
+    } else {
+        unsafe { System.shrink(ptr, old_layout, new_layout) }
+    };
+    new_ptr.unwrap().as_ptr() as *mut u8
+}
+
 // This global keeps track of any join handles produced. It is needed
 // because the minirust intrinsic for spawn only returns an integer and
 // the join only takes an integer, so we have to map these integers to `JoinHandles`
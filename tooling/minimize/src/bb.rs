@@ -4,10 +4,12 @@ use crate::*;
 // Those can be found by grepping "IGNORED".
 
 /// A MIR statement becomes either a MiniRust statement or an intrinsic with some arguments, which
-/// then starts a new basic block.
+/// then starts a new basic block. It can also become a few statements followed by a terminator
+/// leading into other blocks, which eventually continue at `next_block`.
 enum StatementResult {
     Statement(Statement),
     Intrinsic { intrinsic: IntrinsicOp, destination: PlaceExpr, arguments: List<ValueExpr> },
+    Terminator { stmts: List<Statement>, terminator: Terminator, next_block: BbName },
 }
 
 /// A MIR terminator becomes a MiniRust terminator, possibly preceded by a list
@@ -45,6 +47,17 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                     cur_block_name = next_bb;
                     cur_block_statements = List::new();
                 }
+                StatementResult::Terminator { stmts, terminator, next_block } => {
+                    for stmt in stmts.iter() {
+                        cur_block_statements.push(stmt);
+                    }
+                    let cur_block = BasicBlock { statements: cur_block_statements, terminator };
+                    let old = self.blocks.insert(cur_block_name, cur_block);
+                    assert!(old.is_none()); // make sure we do not overwrite a bb
+                    // Go on building the next block.
+                    cur_block_name = next_block;
+                    cur_block_statements = List::new();
+                }
             }
         }
         let TerminatorResult { stmts, terminator } = self.translate_terminator(bb.terminator());
@@ -120,8 +133,50 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                             arguments: list![op],
                         };
                     }
-                    rs::NonDivergingIntrinsic::CopyNonOverlapping(_) =>
-                        rs::span_bug!(span, "NonDivergingIntrinsic not supported: {intrinsic:?}"),
+                    rs::NonDivergingIntrinsic::CopyNonOverlapping(copy) => {
+                        let pointee =
+                            copy.src.ty(&self.body, self.tcx).builtin_deref(true).unwrap();
+                        let pointee = self.rs_layout_of(pointee);
+                        assert!(pointee.is_sized());
+                        let size = build::const_int_typed::<usize>(Int::from(pointee.size.bytes()));
+                        let align = Int::from(pointee.align.abi.bytes());
+                        let src = self.translate_operand(&copy.src, span);
+                        let dst = self.translate_operand(&copy.dst, span);
+                        let count = self.translate_operand(&copy.count, span);
+                        let len = build::mul_unchecked(count, size);
+
+                        // Both pointers must be aligned and non-null (even if `count` is 0),
+                        // and the two ranges must not overlap.
+                        let precondition = build::bool_and(
+                            build::bool_and(
+                                ptr_aligned_non_null(src, align),
+                                ptr_aligned_non_null(dst, align),
+                            ),
+                            ranges_disjoint(src, dst, len),
+                        );
+                        // Copy each byte as a union, which preserves provenance
+                        // and uninitialized bytes.
+                        let byte_ty = build::union_ty(
+                            &[(Size::ZERO, <u8>::get_type())],
+                            Size::from_bytes_const(1),
+                            Align::ONE,
+                        );
+                        let next_block = self.fresh_bb_name();
+                        let (stmts, terminator) =
+                            self.translate_byte_loop(precondition, len, next_block, |i| {
+                                let byte = |ptr| {
+                                    build::deref(
+                                        build::ptr_offset(ptr, i, build::InBounds::No),
+                                        byte_ty,
+                                    )
+                                };
+                                list![Statement::Assign {
+                                    destination: byte(dst),
+                                    source: build::load(byte(src))
+                                }]
+                            });
+                        return StatementResult::Terminator { stmts, terminator, next_block };
+                    }
                 }
             }
             rs::StatementKind::PlaceMention(place) => {
@@ -298,6 +353,13 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 };
                 return TerminatorResult { terminator, stmts: List::new() };
             }
+            rs::sym::caller_location => {
+                let destination = self.translate_place(destination, span);
+                let stmt = Statement::Assign { destination, source: self.caller_location(span) };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
             rs::sym::raw_eq =>
                 return TerminatorResult {
                     stmts: List::new(),
@@ -311,6 +373,29 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         next_block: target.as_ref().map(|t| self.bb_name_map[t]),
                     },
                 },
+            rs::sym::typed_swap => {
+                // `Swap` needs to know the pointee layout, so we pass the raw pointers as
+                // mutable references. `typed_swap` requires them to be valid for that anyway.
+                let pointee = intrinsic.args.type_at(0);
+                let ref_ty = Type::Ptr(PtrType::Ref {
+                    pointee: self.pointee_info_of(pointee, span),
+                    mutbl: Mutability::Mutable,
+                });
+                let arguments = args
+                    .iter()
+                    .map(|x| build::transmute(self.translate_operand(&x.node, x.span), ref_ty))
+                    .collect();
+
+                TerminatorResult {
+                    stmts: List::new(),
+                    terminator: Terminator::Intrinsic {
+                        intrinsic: IntrinsicOp::Swap,
+                        arguments,
+                        ret: unit_place(),
+                        next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+                    },
+                }
+            }
             rs::sym::arith_offset => {
                 let lty = args[0].node.ty(&self.body, self.tcx);
                 let rty = args[1].node.ty(&self.body, self.tcx);
@@ -333,6 +418,54 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
                 return TerminatorResult { stmts: list!(stmt), terminator };
             }
+            // rustc usually lowers these two already, but if not, they are a typed load and store
+            // through the pointer (unlike `copy_nonoverlapping`, which copies bytes).
+            // MiniRust has no volatile accesses; they behave like normal ones.
+            rs::sym::read_via_copy | rs::sym::volatile_load => {
+                let pointee = intrinsic.args.type_at(0);
+                let ptr = self.translate_operand(&args[0].node, span);
+                let destination = self.translate_place(destination, span);
+
+                let source = build::load(build::deref(ptr, self.translate_ty(pointee, span)));
+                let stmt = Statement::Assign { destination, source };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
+            rs::sym::write_via_move | rs::sym::volatile_store => {
+                let pointee = intrinsic.args.type_at(0);
+                let ptr = self.translate_operand(&args[0].node, span);
+                let value = self.translate_operand(&args[1].node, span);
+
+                let destination = build::deref(ptr, self.translate_ty(pointee, span));
+                let stmt = Statement::Assign { destination, source: value };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
+            rs::sym::write_bytes => {
+                let pointee = self.rs_layout_of(intrinsic.args.type_at(0));
+                assert!(pointee.is_sized());
+                let size = build::const_int_typed::<usize>(Int::from(pointee.size.bytes()));
+                let align = Int::from(pointee.align.abi.bytes());
+                let dst = self.translate_operand(&args[0].node, span);
+                let val = self.translate_operand(&args[1].node, span);
+                let count = self.translate_operand(&args[2].node, span);
+
+                // `dst` must be aligned and non-null, even if `count` is 0.
+                let precondition = ptr_aligned_non_null(dst, align);
+                let next_block = self.bb_name_map[&target.unwrap()];
+                let len = build::mul_unchecked(count, size);
+                let (stmts, terminator) =
+                    self.translate_byte_loop(precondition, len, next_block, |i| {
+                        let byte = build::ptr_offset(dst, i, build::InBounds::No);
+                        list![Statement::Assign {
+                            destination: build::deref(byte, <u8>::get_type()),
+                            source: val
+                        }]
+                    });
+                TerminatorResult { stmts, terminator }
+            }
             rs::sym::ptr_offset_from | rs::sym::ptr_offset_from_unsigned => {
                 let unsigned = intrinsic_name == rs::sym::ptr_offset_from_unsigned;
                 let lty = args[0].node.ty(&self.body, self.tcx);
@@ -370,6 +503,28 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
                 return TerminatorResult { stmts: list!(stmt), terminator };
             }
+            rs::sym::cttz | rs::sym::cttz_nonzero => {
+                let v = self.translate_operand(&args[0].node, span);
+                let destination = self.translate_place(destination, span);
+
+                let stmt = Statement::Assign { destination, source: build::trailing_zeros(v) };
+                let next_block = self.bb_name_map[&target.unwrap()];
+                let terminator = if intrinsic_name == rs::sym::cttz_nonzero {
+                    // Calling `cttz_nonzero` on `0` is UB.
+                    let rty = args[0].node.ty(&self.body, self.tcx);
+                    let ty = self.translate_ty(rty, span);
+                    let zero = ValueExpr::Constant(Constant::Int(Int::ZERO), ty);
+                    Terminator::Intrinsic {
+                        intrinsic: IntrinsicOp::Assume,
+                        arguments: list![build::ne(v, zero)],
+                        ret: build::unit_place(),
+                        next_block: Some(next_block),
+                    }
+                } else {
+                    Terminator::Goto(next_block)
+                };
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
             rs::sym::exact_div => {
                 let l = self.translate_operand(&args[0].node, span);
                 let r = self.translate_operand(&args[1].node, span);
@@ -404,6 +559,43 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
                 TerminatorResult { stmts: list!(stmt), terminator }
             }
+            rs::sym::type_id | rs::sym::type_name => {
+                // Both only depend on the generic argument, so we let rustc evaluate them.
+                // `type_id` becomes an integer constant that is distinct for distinct types,
+                // `type_name` becomes a `&str` pointing to a global holding the name.
+                let val = self.tcx.const_eval_instance(param_env, intrinsic, span).unwrap();
+                let ret_ty = destination.ty(&self.body, self.tcx).ty;
+                let destination = self.translate_place(destination, span);
+                let val = self.translate_const(&rs::mir::Const::Val(val, ret_ty), span);
+
+                let stmt = Statement::Assign { destination, source: val };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
+            rs::sym::simd_add
+            | rs::sym::simd_sub
+            | rs::sym::simd_mul
+            | rs::sym::simd_and
+            | rs::sym::simd_or
+            | rs::sym::simd_xor => {
+                let op = match intrinsic_name {
+                    rs::sym::simd_add => IntBinOp::Add,
+                    rs::sym::simd_sub => IntBinOp::Sub,
+                    rs::sym::simd_mul => IntBinOp::Mul,
+                    rs::sym::simd_and => IntBinOp::BitAnd,
+                    rs::sym::simd_or => IntBinOp::BitOr,
+                    rs::sym::simd_xor => IntBinOp::BitXor,
+                    _ => unreachable!(),
+                };
+                let stmts = self.translate_simd_binop(op, args, destination, span);
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+                TerminatorResult { stmts, terminator }
+            }
+            rs::sym::simd_eq => {
+                let stmts = self.translate_simd_eq(args, destination, span);
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+                TerminatorResult { stmts, terminator }
+            }
             rs::sym::unlikely | rs::sym::likely => {
                 // FIXME: use the "fallback body" provided in the standard library.
                 let destination = self.translate_place(&destination, span);
@@ -414,10 +606,110 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
                 return TerminatorResult { stmts: list!(stmt), terminator };
             }
-            name => rs::span_bug!(span, "unsupported Rust intrinsic `{}`", name),
+            rs::sym::select_unpredictable => {
+                // The hint has no semantic effect, so this is just a branch on the condition.
+                let condition = self.translate_operand(&args[0].node, span);
+                let destination = self.translate_place(destination, span);
+                let next_block = self.bb_name_map[&target.unwrap()];
+
+                let mut select_bb = |val: &rs::Operand<'tcx>| {
+                    let source = self.translate_operand(val, span);
+                    let bb = self.fresh_bb_name();
+                    let block = BasicBlock {
+                        statements: list![Statement::Assign { destination, source }],
+                        terminator: Terminator::Goto(next_block),
+                    };
+                    self.blocks.try_insert(bb, block).unwrap();
+                    bb
+                };
+                let true_bb = select_bb(&args[1].node);
+                let false_bb = select_bb(&args[2].node);
+
+                let terminator = Terminator::Switch {
+                    value: build::bool_to_int::<u8>(condition),
+                    cases: [(Int::from(1), true_bb)].into_iter().collect(),
+                    fallback: false_bb,
+                };
+                TerminatorResult { stmts: List::new(), terminator }
+            }
+            rs::sym::is_val_statically_known => {
+                // This may always return `false`; the library then takes its general code path
+                // (e.g. the multiplication loop in `pow`).
+                let destination = self.translate_place(destination, span);
+                let stmt = Statement::Assign { destination, source: build::const_bool(false) };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
+            rs::sym::ptr_guaranteed_cmp => {
+                // Returns `1` for equal and `0` for unequal pointers. `2` ("unknown") is only
+                // needed during const-eval; at runtime all addresses are known.
+                let l = self.translate_operand(&args[0].node, span);
+                let r = self.translate_operand(&args[1].node, span);
+                let destination = self.translate_place(destination, span);
+
+                let source = build::bool_to_int::<u8>(build::eq(l, r));
+
+                let stmt = Statement::Assign { destination, source };
+                let terminator = Terminator::Goto(self.bb_name_map[&target.unwrap()]);
+                TerminatorResult { stmts: list!(stmt), terminator }
+            }
+            name => show_error!("unsupported Rust intrinsic `{name}`"),
         }
     }
 
+    /// MiniRust has no `memset` or `memcpy`, so intrinsics like these operate on one byte at a time.
+    /// This builds the loop `assume(precondition); i = len; while i != 0 { i -= 1; body(i) }`,
+    /// which continues at `next_block` when done. `body` is given the value of `i`.
+    ///
+    /// Returns the statements initializing the loop and the terminator to end the block with.
+    fn translate_byte_loop(
+        &mut self,
+        precondition: ValueExpr,
+        len: ValueExpr,
+        next_block: BbName,
+        body: impl FnOnce(ValueExpr) -> List<Statement>,
+    ) -> (List<Statement>, Terminator) {
+        let i = self.fresh_local(<usize>::get_type());
+        let i_val = build::load(build::local_by_name(i));
+
+        let head_bb = self.fresh_bb_name();
+        let body_bb = self.fresh_bb_name();
+        let exit_bb = self.fresh_bb_name();
+
+        let mut statements = list![Statement::Assign {
+            destination: build::local_by_name(i),
+            source: build::sub_unchecked(i_val, build::const_int(1_usize))
+        }];
+        for stmt in body(i_val).iter() {
+            statements.push(stmt);
+        }
+        let body_block = BasicBlock { statements, terminator: Terminator::Goto(head_bb) };
+        let head_block = BasicBlock {
+            statements: list![],
+            terminator: Terminator::Switch {
+                value: build::bool_to_int::<u8>(build::eq(i_val, build::const_int(0_usize))),
+                cases: [(Int::from(1), exit_bb)].into_iter().collect(),
+                fallback: body_bb,
+            },
+        };
+        let exit_block = BasicBlock {
+            statements: list![Statement::StorageDead(i)],
+            terminator: Terminator::Goto(next_block),
+        };
+        self.blocks.try_insert(head_bb, head_block).unwrap();
+        self.blocks.try_insert(body_bb, body_block).unwrap();
+        self.blocks.try_insert(exit_bb, exit_block).unwrap();
+
+        let init = Statement::Assign { destination: build::local_by_name(i), source: len };
+        let terminator = Terminator::Intrinsic {
+            intrinsic: IntrinsicOp::Assume,
+            arguments: list![precondition],
+            ret: build::unit_place(),
+            next_block: Some(head_bb),
+        };
+        (list![Statement::StorageLive(i), init], terminator)
+    }
+
     fn translate_call(
         &mut self,
         func: &rs::Operand<'tcx>,
@@ -426,10 +718,27 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         target: &Option<rs::BasicBlock>,
         span: rs::Span,
     ) -> TerminatorResult {
-        // For now we only support calling specific functions, not function pointers.
-        let rs::Operand::Constant(box f1) = func else { panic!() };
-        let rs::mir::Const::Val(_, f2) = f1.const_ else { panic!() };
-        let &rs::TyKind::FnDef(f, substs_ref) = f2.kind() else { panic!() };
+        let func_ty = func.ty(&self.body, self.tcx);
+        if func_ty.is_fn_ptr() {
+            // A call through a function pointer.
+            let sig = func_ty.fn_sig(self.tcx);
+            let abi = self
+                .tcx
+                .fn_abi_of_fn_ptr(rs::ParamEnv::reveal_all().and((sig, rs::List::empty())))
+                .unwrap();
+            let terminator = Terminator::Call {
+                callee: self.translate_operand(func, span),
+                calling_convention: translate_calling_convention(abi.conv),
+                arguments: self.translate_args(rs_args),
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            };
+            return TerminatorResult { terminator, stmts: List::new() };
+        }
+
+        // Otherwise, we are calling a specific function. Its function item type tells us which
+        // one: the callee is usually a constant, but shims also call locals of that type.
+        let &rs::TyKind::FnDef(f, substs_ref) = func_ty.kind() else { panic!() };
         let param_env = rs::ParamEnv::reveal_all();
         let instance = rs::Instance::expect_resolve(self.tcx, param_env, f, substs_ref, span);
 
@@ -438,15 +747,36 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             return self.translate_rs_intrinsic(instance, rs_args, destination, target, span);
         }
 
+        if let Some(result) =
+            self.translate_int_min_max_call(instance, rs_args, destination, target, span)
+        {
+            return result;
+        }
+
+        if let Some(result) =
+            self.translate_slice_len_call(instance, rs_args, destination, target, span)
+        {
+            return result;
+        }
+
+        if let Some(result) =
+            self.translate_layout_check_call(instance, rs_args, destination, target, span)
+        {
+            return result;
+        }
+
         let terminator = if self.tcx.crate_name(f.krate).as_str() == "intrinsics" {
             // Direct call to a MiniRust intrinsic.
             let intrinsic = match self.tcx.item_name(f).as_str() {
                 "print" => IntrinsicOp::PrintStdout,
                 "eprint" => IntrinsicOp::PrintStderr,
-                "exit" => IntrinsicOp::Exit,
+                "write" => IntrinsicOp::Write,
+                "exit" | "exit_code" => IntrinsicOp::Exit,
                 "panic" => IntrinsicOp::Panic,
                 "allocate" => IntrinsicOp::Allocate,
+                "allocate_zeroed" => IntrinsicOp::AllocateZeroed,
                 "deallocate" => IntrinsicOp::Deallocate,
+                "reallocate" => IntrinsicOp::Reallocate,
                 "spawn" => IntrinsicOp::Spawn,
                 "join" => IntrinsicOp::Join,
                 "create_lock" => IntrinsicOp::Lock(IntrinsicLockOp::Create),
@@ -468,7 +798,19 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 ret: self.translate_place(&destination, span),
                 next_block: target.as_ref().map(|t| self.bb_name_map[t]),
             }
-        } else if is_panic_fn(&instance.to_string()) {
+        } else if let Some(intrinsic) = self.allocator_shim_intrinsic(f) {
+            // The global allocator entry points have no MIR, but take the same arguments
+            // as the corresponding MiniRust heap intrinsics.
+            Terminator::Intrinsic {
+                intrinsic,
+                arguments: rs_args
+                    .iter()
+                    .map(|x| self.translate_operand(&x.node, x.span))
+                    .collect(),
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            }
+        } else if is_panic_fn(&rs::with_no_visible_paths!(instance.to_string())) {
             // We can't translate this call, it takes a string. As a hack we just ignore the argument.
             Terminator::Intrinsic {
                 intrinsic: IntrinsicOp::Panic,
@@ -484,16 +826,44 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 .unwrap();
             let conv = translate_calling_convention(abi.conv);
 
-            let mut args: List<_> = rs_args
-                .iter()
-                .map(|x| {
-                    match &x.node {
-                        rs::Operand::Move(place) =>
-                            ArgumentExpr::InPlace(self.translate_place(place, x.span)),
-                        op => ArgumentExpr::ByValue(self.translate_operand(op, x.span)),
+            // The "rust-call" ABI passes the last argument as a tuple, but the callee
+            // receives its elements as separate arguments.
+            let (rs_args, untupled_arg) = if func_ty.fn_sig(self.tcx).abi() == rs::Abi::RustCall {
+                let (tuple, rs_args) = rs_args.split_last().unwrap();
+                (rs_args, Some(tuple))
+            } else {
+                (rs_args, None)
+            };
+
+            let mut args = self.translate_args(rs_args);
+
+            if let Some(tuple) = untupled_arg {
+                match &tuple.node {
+                    rs::Operand::Move(place) | rs::Operand::Copy(place) => {
+                        let rs::TyKind::Tuple(elems) = place.ty(&self.body, self.tcx).ty.kind()
+                        else {
+                            rs::span_bug!(tuple.span, "untupled argument is not a tuple")
+                        };
+                        let place = self.translate_place(place, tuple.span);
+                        for i in 0..elems.len() {
+                            args.push(ArgumentExpr::ByValue(build::load(build::field(place, i))));
+                        }
                     }
-                })
-                .collect();
+                    op => {
+                        let ValueExpr::Tuple(elems, _) = self.translate_operand(op, tuple.span)
+                        else {
+                            rs::span_bug!(tuple.span, "untupled argument is not a tuple")
+                        };
+                        for elem in elems.iter() {
+                            args.push(ArgumentExpr::ByValue(elem));
+                        }
+                    }
+                }
+            }
+
+            if instance.def.requires_caller_location(self.tcx) {
+                args.push(ArgumentExpr::ByValue(self.caller_location(span)));
+            }
 
             // Distinguish direct function calls or dynamic dispatch on a trait object.
             let callee = if let rs::InstanceKind::Virtual(_trait, method) = instance.def {
@@ -525,12 +895,205 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         };
         TerminatorResult { terminator, stmts: List::new() }
     }
+
+    /// The `&'static Location` that `#[track_caller]` code observes when called at `span`.
+    /// Inside a `#[track_caller]` function this forwards the location we received from our
+    /// caller; otherwise it is the location of `span` itself. (We do not run MIR inlining, so
+    /// unlike rustc we do not need to walk inlined scopes here.)
+    fn caller_location(&mut self, span: rs::Span) -> ValueExpr {
+        if let Some(caller_location) = self.caller_location {
+            return build::load(build::local_by_name(caller_location));
+        }
+        let location = self.tcx.span_as_caller_location(span);
+        self.translate_const(&rs::mir::Const::Val(location, self.tcx.caller_location_ty()), span)
+    }
+
+    /// Lowers `cmp::{min, max}` and `Ord::{min, max, clamp}` on integers to `IntBinOp::{Min, Max}`
+    /// instead of calling the generic library functions.
+    fn translate_int_min_max_call(
+        &mut self,
+        instance: rs::Instance<'tcx>,
+        rs_args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        target: &Option<rs::BasicBlock>,
+        span: rs::Span,
+    ) -> Option<TerminatorResult> {
+        let tcx = self.tcx;
+        let def_id = instance.def_id();
+        let is_item = |items: [rs::Symbol; 2]| {
+            items.into_iter().any(|item| tcx.is_diagnostic_item(item, def_id))
+        };
+        let is_ord_clamp = tcx
+            .trait_of_item(def_id)
+            .is_some_and(|trait_| tcx.is_diagnostic_item(rs::sym::Ord, trait_))
+            && tcx.item_name(def_id).as_str() == "clamp";
+
+        let op = if is_item([rs::sym::cmp_min, rs::sym::cmp_ord_min]) {
+            Some(IntBinOp::Min)
+        } else if is_item([rs::sym::cmp_max, rs::sym::cmp_ord_max]) {
+            Some(IntBinOp::Max)
+        } else if is_ord_clamp {
+            None
+        } else {
+            return None;
+        };
+        if !instance.args.type_at(0).is_integral() {
+            return None;
+        }
+
+        let args: Vec<ValueExpr> =
+            rs_args.iter().map(|x| self.translate_operand(&x.node, x.span)).collect();
+        let destination = self.translate_place(destination, span);
+        let next_block = self.bb_name_map[target.as_ref().unwrap()];
+
+        match op {
+            Some(op) => {
+                let source = ValueExpr::BinOp {
+                    operator: BinOp::Int(op),
+                    left: GcCow::new(args[0]),
+                    right: GcCow::new(args[1]),
+                };
+                Some(TerminatorResult {
+                    stmts: list![Statement::Assign { destination, source }],
+                    terminator: Terminator::Goto(next_block),
+                })
+            }
+            None => {
+                // `clamp` panics unless `min <= max`.
+                let [val, min, max] = args[..] else {
+                    rs::span_bug!(span, "`clamp` takes three arguments")
+                };
+                let clamp_bb = self.fresh_bb_name();
+                let source = build::int_min(build::int_max(val, min), max);
+                let clamp_block = BasicBlock {
+                    statements: list![Statement::Assign { destination, source }],
+                    terminator: Terminator::Goto(next_block),
+                };
+                self.blocks.try_insert(clamp_bb, clamp_block).unwrap();
+
+                let panic_bb = self.fresh_bb_name();
+                let panic_block = BasicBlock { statements: list![], terminator: build::panic() };
+                self.blocks.try_insert(panic_bb, panic_block).unwrap();
+
+                let terminator = Terminator::Switch {
+                    value: build::bool_to_int::<u8>(build::le(min, max)),
+                    cases: [(Int::from(1), clamp_bb)].into_iter().collect(),
+                    fallback: panic_bb,
+                };
+                Some(TerminatorResult { terminator, stmts: List::new() })
+            }
+        }
+    }
+
+    /// Translates calls to `<[T]>::len` and `str::len` to a read of the pointer metadata,
+    /// rather than calling into the library.
+    fn translate_slice_len_call(
+        &mut self,
+        instance: rs::Instance<'tcx>,
+        rs_args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        target: &Option<rs::BasicBlock>,
+        span: rs::Span,
+    ) -> Option<TerminatorResult> {
+        let def_id = instance.def_id();
+        let impl_ = self.tcx.impl_of_method(def_id)?;
+        if self.tcx.item_name(def_id).as_str() != "len"
+            || self.tcx.trait_id_of_impl(impl_).is_some()
+        {
+            return None;
+        }
+        let self_ty = self.tcx.type_of(impl_).instantiate_identity();
+        if !matches!(self_ty.kind(), rs::TyKind::Slice(_) | rs::TyKind::Str) {
+            return None;
+        }
+
+        let [arg] = rs_args else { rs::span_bug!(span, "`len` takes one argument") };
+        let source = build::get_metadata(self.translate_operand(&arg.node, arg.span));
+        let destination = self.translate_place(destination, span);
+        let next_block = self.bb_name_map[target.as_ref().unwrap()];
+        Some(TerminatorResult {
+            stmts: list![Statement::Assign { destination, source }],
+            terminator: Terminator::Goto(next_block),
+        })
+    }
+
+    /// Translates calls to `Layout::is_size_align_valid`, which has no MIR, to the check it performs:
+    /// `align` is a power of two and `size` rounded up to `align` does not exceed `isize::MAX`.
+    fn translate_layout_check_call(
+        &mut self,
+        instance: rs::Instance<'tcx>,
+        rs_args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        target: &Option<rs::BasicBlock>,
+        span: rs::Span,
+    ) -> Option<TerminatorResult> {
+        let tcx = self.tcx;
+        let def_id = instance.def_id();
+        let is_layout_method = tcx.impl_of_method(def_id).is_some_and(|impl_| {
+            let self_ty = tcx.type_of(impl_).instantiate_identity();
+            self_ty
+                .ty_adt_def()
+                .is_some_and(|adt| Some(adt.did()) == tcx.lang_items().alloc_layout())
+        });
+        if !is_layout_method || tcx.item_name(def_id).as_str() != "is_size_align_valid" {
+            return None;
+        }
+
+        let [size, align] = rs_args else {
+            rs::span_bug!(span, "`is_size_align_valid` takes two arguments")
+        };
+        let size = self.translate_operand(&size.node, size.span);
+        let align = self.translate_operand(&align.node, align.span);
+        let align_is_pow2 = build::eq(build::count_ones(align), build::const_int(1u32));
+        // For a power of two, this cannot underflow.
+        let max_size = build::sub(
+            build::const_int_typed::<usize>(Int::from(isize::MAX)),
+            build::sub(align, build::const_int(1usize)),
+        );
+        let source = build::bool_and(align_is_pow2, build::le(size, max_size));
+        let destination = self.translate_place(destination, span);
+        let next_block = self.bb_name_map[target.as_ref().unwrap()];
+        Some(TerminatorResult {
+            stmts: list![Statement::Assign { destination, source }],
+            terminator: Terminator::Goto(next_block),
+        })
+    }
+
+    /// Returns the heap intrinsic implementing the global allocator function `f`, if it is one.
+    fn allocator_shim_intrinsic(&self, f: rs::DefId) -> Option<IntrinsicOp> {
+        if !self.tcx.is_foreign_item(f) {
+            return None;
+        }
+        let intrinsic = match self.tcx.item_name(f).as_str() {
+            "__rust_alloc" => IntrinsicOp::Allocate,
+            "__rust_alloc_zeroed" => IntrinsicOp::AllocateZeroed,
+            "__rust_dealloc" => IntrinsicOp::Deallocate,
+            "__rust_realloc" => IntrinsicOp::Reallocate,
+            _ => return None,
+        };
+        Some(intrinsic)
+    }
+
+    fn translate_args(&mut self, rs_args: &[rs::Spanned<rs::Operand<'tcx>>]) -> List<ArgumentExpr> {
+        rs_args
+            .iter()
+            .map(|x| {
+                match &x.node {
+                    rs::Operand::Move(place) =>
+                        ArgumentExpr::InPlace(self.translate_place(place, x.span)),
+                    op => ArgumentExpr::ByValue(self.translate_operand(op, x.span)),
+                }
+            })
+            .collect()
+    }
 }
 
 // HACK to skip translating some functions we can't handle yet.
 // These always panic so we just turn them into the panic intrinsic.
 fn is_panic_fn(name: &str) -> bool {
     let fns = [
+        "alloc::alloc::handle_alloc_error",
+        "alloc::raw_vec::handle_error",
         "core::panicking::panic",
         "core::panicking::panic_fmt",
         "core::panicking::panic_nounwind",
@@ -541,3 +1104,24 @@ fn is_panic_fn(name: &str) -> bool {
     ];
     fns.contains(&name)
 }
+
+/// Whether the pointer `ptr` is non-null and aligned to `align`.
+fn ptr_aligned_non_null(ptr: ValueExpr, align: Int) -> ValueExpr {
+    let addr = build::ptr_addr(ptr);
+    build::bool_and(
+        build::ne(addr, build::const_int(0_usize)),
+        build::eq(
+            build::rem(addr, build::const_int_typed::<usize>(align)),
+            build::const_int(0_usize),
+        ),
+    )
+}
+
+/// Whether the ranges of `len` bytes starting at the pointers `l` and `r` do not overlap.
+fn ranges_disjoint(l: ValueExpr, r: ValueExpr, len: ValueExpr) -> ValueExpr {
+    let (l, r) = (build::ptr_addr(l), build::ptr_addr(r));
+    build::bool_or(
+        build::bool_and(build::le(r, l), build::ge(build::sub(l, r), len)),
+        build::bool_and(build::lt(l, r), build::ge(build::sub(r, l), len)),
+    )
+}
@@ -45,7 +45,8 @@ fn mark_used_bytes(ty: Type, markers: &mut [bool]) {
     match ty {
         Type::Int(int_ty) => mark_size(int_ty.size, markers),
         Type::Bool => mark_size(Size::from_bytes_const(1), markers),
-        Type::Ptr(_) => mark_size(DefaultTarget::PTR_SIZE, markers),
+        Type::Ptr(_) =>
+            mark_size(ty.layout::<DefaultTarget>().expect_size("pointers are sized"), markers),
         Type::Tuple { sized_fields, unsized_field, .. } => {
             assert!(unsized_field.extract().is_none(), "unsized types cannot be part of unions");
             for (offset, ty) in sized_fields {
@@ -37,10 +37,6 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 ValueExpr::Constant(Constant::Bool(val), ty)
             }
             Type::Ptr(ptr_ty) => {
-                if let PtrType::FnPtr = ptr_ty {
-                    rs::span_bug!(span, "Function pointers are currently not supported")
-                }
-
                 let (thin_ptr, meta) = match ptr_ty.meta_kind() {
                     PointerMetaKind::None => (ecx.read_pointer(&val).unwrap(), None),
                     PointerMetaKind::ElementCount => {
@@ -48,11 +44,20 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         let el_count = meta.unwrap_meta().to_target_usize(ecx).unwrap();
                         (thin.to_pointer(ecx).unwrap(), Some(build::const_int(el_count)))
                     }
-                    PointerMetaKind::VTablePointer(..) =>
-                        rs::span_bug!(
-                            span,
-                            "Constant trait object pointers are currently not supported"
-                        ),
+                    PointerMetaKind::VTablePointer(trait_name) => {
+                        let (thin, meta) = ecx.read_immediate(&val).unwrap().to_scalar_and_meta();
+                        let vtable_ptr = meta.unwrap_meta().to_pointer(ecx).unwrap();
+                        let (Some(prov), _) = vtable_ptr.into_parts() else {
+                            rs::span_bug!(span, "vtable pointer without provenance")
+                        };
+                        let RelocationTarget::VTable(vtable_name) =
+                            self.translate_relocation_target(prov.alloc_id(), rs::Size::ZERO)
+                        else {
+                            rs::span_bug!(span, "trait object metadata does not point to a vtable")
+                        };
+                        let meta = build::const_vtable(vtable_name, trait_name);
+                        (thin.to_pointer(ecx).unwrap(), Some(meta))
+                    }
                 };
                 // Depending on the provenance of the pointer, a different constant is built.
                 let (prov, offset) = thin_ptr.into_parts();
@@ -61,11 +66,13 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         let addr: Int = offset.bytes_usize().into();
                         Constant::PointerWithoutProvenance(addr)
                     }
-                    Some(prov) => {
-                        let alloc_id = prov.alloc_id();
-                        let rel = self.translate_relocation(alloc_id, offset);
-                        Constant::GlobalPointer(rel)
-                    }
+                    Some(prov) =>
+                        match self.translate_relocation_target(prov.alloc_id(), offset) {
+                            RelocationTarget::Global(rel) => Constant::GlobalPointer(rel),
+                            RelocationTarget::Fn(fn_name) => Constant::FnPointer(fn_name),
+                            RelocationTarget::VTable(vtable_name) =>
+                                Constant::VTablePointer(vtable_name),
+                        },
                 };
                 // A thin pointer is directly a constant, a wide pointer is first aggregated from the parts.
                 match meta {
@@ -132,6 +139,36 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         Relocation { name, offset }
     }
 
+    /// Translates a pointer into `alloc_id`, which may be a global allocation, a function, or a vtable.
+    fn translate_relocation_target(
+        &mut self,
+        alloc_id: rs::AllocId,
+        offset: rs::Size,
+    ) -> RelocationTarget {
+        match self.tcx.global_alloc(alloc_id) {
+            rs::GlobalAlloc::Function { instance } => {
+                if offset != rs::Size::ZERO {
+                    show_error!("unsupported: offset pointer to a function in a constant");
+                }
+                RelocationTarget::Fn(self.get_fn_name(instance))
+            }
+            rs::GlobalAlloc::VTable(ty, preds) => {
+                if offset != rs::Size::ZERO {
+                    show_error!("unsupported: offset pointer to a vtable in a constant");
+                }
+                let trait_obj_ty = rs::Ty::new_dynamic(
+                    self.tcx,
+                    preds,
+                    self.tcx.lifetimes.re_erased,
+                    rs::DynKind::Dyn,
+                );
+                RelocationTarget::VTable(self.get_vtable(ty, trait_obj_ty))
+            }
+            rs::GlobalAlloc::Static(_) | rs::GlobalAlloc::Memory(_) =>
+                RelocationTarget::Global(self.translate_relocation(alloc_id, offset)),
+        }
+    }
+
     // calls `translate_const_allocation` with the allocation of alloc_id,
     // and adds the alloc_id and its newly-created global to alloc_map.
     fn translate_alloc_id(&mut self, alloc_id: rs::AllocId) -> GlobalName {
@@ -144,13 +181,34 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
         let alloc = match self.tcx.global_alloc(alloc_id) {
             rs::GlobalAlloc::Memory(alloc) => alloc,
+            rs::GlobalAlloc::Static(def_id) if self.tcx.is_foreign_item(def_id) => {
+                self.translate_foreign_static(def_id, name);
+                return name;
+            }
             rs::GlobalAlloc::Static(def_id) => self.tcx.eval_static_initializer(def_id).unwrap(),
-            _ => panic!("unsupported!"),
+            alloc => show_error!("unsupported global allocation: {alloc:?}"),
         };
         self.translate_const_allocation(alloc, name);
         name
     }
 
+    // Extern statics have no initializer. The only one we support is the marker static the
+    // allocator shims read (with a volatile load) before allocating; its value does not matter.
+    fn translate_foreign_static(&mut self, def_id: rs::DefId, name: GlobalName) {
+        let is_alloc_shim_marker = self.tcx.crate_name(def_id.krate) == rs::sym::alloc
+            && self.tcx.item_name(def_id).as_str() == "__rust_no_alloc_shim_is_unstable";
+        if !is_alloc_shim_marker {
+            show_error!("unsupported: extern static `{}`", self.tcx.def_path_str(def_id));
+        }
+        let ty = self.tcx.type_of(def_id).instantiate_identity();
+        let layout = self.rs_layout_of(ty);
+        let bytes = vec![Some(0u8); layout.size().bytes_usize()];
+        let align = translate_align(layout.align().abi);
+        let global =
+            Global { bytes: bytes.into_iter().collect(), relocations: Default::default(), align };
+        self.cx.globals.insert(name, global);
+    }
+
     // adds a Global representing this ConstAllocation, and returns the corresponding GlobalName.
     fn translate_const_allocation(
         &mut self,
@@ -183,7 +241,7 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let inner_offset = bytes[start..end].iter().map(|x| x.unwrap()).collect();
                 let inner_offset = DefaultTarget::ENDIANNESS.decode(Unsigned, inner_offset);
                 let inner_offset = rs::Size::from_bytes(inner_offset.try_to_usize().unwrap());
-                let relo = self.translate_relocation(alloc_id.alloc_id(), inner_offset);
+                let relo = self.translate_relocation_target(alloc_id.alloc_id(), inner_offset);
 
                 let offset = translate_size(offset);
                 (offset, relo)
@@ -20,6 +20,9 @@ impl<'tcx> Ctxt<'tcx> {
         };
 
         let (variants, discriminator) = match layout.variants() {
+            // An enum without variants (like `Infallible`) is uninhabited, just like `!`.
+            rs::Variants::Single { .. } if adt_def.variants().is_empty() =>
+                (Map::new(), Discriminator::Invalid),
             rs::Variants::Single { index } => {
                 let fields = self.translate_adt_variant_fields(
                     layout.fields(),
@@ -27,11 +30,14 @@ impl<'tcx> Ctxt<'tcx> {
                     sref,
                     span,
                 );
-                let variants = [(Int::ZERO, Variant {
+                // The only inhabited variant need not be the first one (e.g. `Result<!, T>`),
+                // so we use its actual discriminant.
+                let discr_int = self.discriminant_for_variant(ty, *index, span);
+                let variants = [(discr_int, Variant {
                     ty: build::tuple_ty(&fields.iter().collect::<Vec<_>>(), size, align),
                     tagger: Map::new(),
                 })];
-                let discriminator = Discriminator::Known(Int::ZERO);
+                let discriminator = Discriminator::Known(discr_int);
                 (variants.into_iter().collect::<Map<Int, Variant>>(), discriminator)
             }
             rs::Variants::Multiple { tag, tag_encoding, tag_field, variants } => {
@@ -81,8 +87,8 @@ impl<'tcx> Ctxt<'tcx> {
                             niche_start,
                         } if *untagged_variant != variant_idx => {
                             // this is a tagged variant, meaning that it writes its tag and has a discriminator branch entry.
-                            let discr_int = int_from_bits(discr.val, tag_ty);
-                            let tag_int = (discr_int
+                            // The tag is computed from the variant index, not from the discriminant.
+                            let tag_int = (Int::from(variant_idx.as_usize())
                                 - Int::from(niche_variants.start().as_usize())
                                 + Int::from(*niche_start))
                             .bring_in_bounds(tag_ty.signed, tag_ty.size);
@@ -126,39 +132,18 @@ impl<'tcx> Ctxt<'tcx> {
                     // So we check the valid range of the tag, and add discriminator branches for everything *outside*
                     // that range to declare it invalid.
                     rs::TagEncoding::Niche { untagged_variant, .. } => {
-                        let tag_valid_range = tag.valid_range(&self.tcx);
-                        let start = int_from_bits(tag_valid_range.start, tag_ty);
-                        let end = int_from_bits(tag_valid_range.end, tag_ty);
-                        if start <= end {
-                            // The range of valid values is continuous, so the invalid values are between the ends of the range and the domain.
-                            let rsize = tag.size(&self.tcx);
-                            let min = if tag_ty.signed == Signedness::Signed {
-                                Int::from(rsize.signed_int_min())
-                            } else {
-                                Int::ZERO
-                            };
-                            let max = if tag_ty.signed == Signedness::Signed {
-                                Int::from(rsize.signed_int_max())
-                            } else {
-                                Int::from(rsize.unsigned_int_max())
-                            };
-                            if end < max {
-                                discriminator_branches.insert(
-                                    (end + Int::ONE, max + Int::ONE),
-                                    Discriminator::Invalid,
-                                );
-                            }
-                            if min < start {
-                                discriminator_branches.insert((min, start), Discriminator::Invalid);
-                            }
-                        } else if end + Int::ONE < start {
-                            // The range of valid values wraps around, so the invalid values are between end and start (exclusive).
-                            discriminator_branches
-                                .insert((end + Int::ONE, start), Discriminator::Invalid);
-                        } else {
-                        }
+                        self.insert_invalid_range_branches(
+                            *tag,
+                            tag_ty,
+                            &mut discriminator_branches,
+                        );
 
-                        GcCow::new(Discriminator::Known(untagged_variant.as_usize().into()))
+                        let untagged_discr =
+                            adt_def.discriminant_for_variant(self.tcx, *untagged_variant);
+                        GcCow::new(Discriminator::Known(int_from_bits(
+                            untagged_discr.val,
+                            discriminant_ty,
+                        )))
                     }
                 };
                 let discriminator = Discriminator::Branch {
@@ -175,6 +160,41 @@ impl<'tcx> Ctxt<'tcx> {
         Type::Enum { variants, discriminator, discriminant_ty, size, align }
     }
 
+    /// Adds discriminator branches that declare all values of `scalar` outside of its valid range invalid.
+    pub fn insert_invalid_range_branches(
+        &self,
+        scalar: rs::abi::Scalar,
+        value_ty: IntType,
+        branches: &mut Map<(Int, Int), Discriminator>,
+    ) {
+        let valid_range = scalar.valid_range(&self.tcx);
+        let start = int_from_bits(valid_range.start, value_ty);
+        let end = int_from_bits(valid_range.end, value_ty);
+        if start <= end {
+            // The range of valid values is continuous, so the invalid values are between the ends of the range and the domain.
+            let rsize = scalar.size(&self.tcx);
+            let min = if value_ty.signed == Signedness::Signed {
+                Int::from(rsize.signed_int_min())
+            } else {
+                Int::ZERO
+            };
+            let max = if value_ty.signed == Signedness::Signed {
+                Int::from(rsize.signed_int_max())
+            } else {
+                Int::from(rsize.unsigned_int_max())
+            };
+            if end < max {
+                branches.insert((end + Int::ONE, max + Int::ONE), Discriminator::Invalid);
+            }
+            if min < start {
+                branches.insert((min, start), Discriminator::Invalid);
+            }
+        } else if end + Int::ONE < start {
+            // The range of valid values wraps around, so the invalid values are between end and start (exclusive).
+            branches.insert((end + Int::ONE, start), Discriminator::Invalid);
+        }
+    }
+
     pub fn discriminant_for_variant_smir(
         &mut self,
         ty: smir::Ty,
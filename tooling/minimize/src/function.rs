@@ -4,9 +4,14 @@ use crate::*;
 pub struct FnCtxt<'cx, 'tcx> {
     /// the body we intend to translate. substitutions are already applied.
     pub body: rs::Body<'tcx>,
+    /// the instance we are translating.
+    instance: rs::Instance<'tcx>,
     /// the ABI of this function
     abi: &'tcx rs::FnAbi<'tcx, rs::Ty<'tcx>>,
 
+    /// for `#[track_caller]` functions, the local holding the implicit `&Location` argument.
+    pub caller_location: Option<LocalName>,
+
     /// the list of local variable declarations (StableMIR) used to retrieve the type of some
     /// SMIR constructs.
     pub locals_smir: Vec<smir::LocalDecl>,
@@ -58,7 +63,9 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
         FnCtxt {
             body,
+            instance,
             abi,
+            caller_location: None,
             cx,
             local_name_map: Default::default(),
             bb_name_map: Default::default(),
@@ -75,6 +82,14 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         BbName(Name::from_internal(name))
     }
 
+    /// Declares a new local of type `ty` that does not correspond to any MIR local.
+    pub fn fresh_local(&mut self, ty: Type) -> LocalName {
+        // Locals are numbered consecutively, so the number of locals is the next free name.
+        let name = LocalName(Name::from_internal(self.locals.len().try_to_usize().unwrap() as u32));
+        self.locals.insert(name, ty);
+        name
+    }
+
     /// translates a function body.
     /// Any fn calls occuring during this translation will be added to the `FnNameMap`.
     pub fn translate(mut self) -> Function {
@@ -111,13 +126,44 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         // this block allocates all "always_storage_live_locals",
         // except for those which are implicitly storage live in Minirust;
         // like the return local and function args.
+        let mut statements: List<Statement> = rs::always_storage_live_locals(&self.body)
+            .iter()
+            .map(|loc| self.local_name_map[&loc])
+            .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
+            .map(Statement::StorageLive)
+            .collect();
+
+        // The "rust-call" ABI passes the elements of the spread argument separately,
+        // so we receive them in fresh locals and assemble the tuple ourselves.
+        let mut spread_args = List::new();
+        if let Some(spread_local) = self.body.spread_arg {
+            let spread_name = self.local_name_map[&spread_local];
+            let spread_ty = self.locals.get(spread_name).unwrap();
+            let Type::Tuple { sized_fields, .. } = spread_ty else {
+                panic!("spread argument is not a tuple")
+            };
+            let mut elems = List::new();
+            for (_offset, ty) in sized_fields.iter() {
+                let name = self.fresh_local(ty);
+                spread_args.push(name);
+                elems.push(build::load(build::local_by_name(name)));
+            }
+            statements.push(Statement::StorageLive(spread_name));
+            statements.push(Statement::Assign {
+                destination: build::local_by_name(spread_name),
+                source: ValueExpr::Tuple(elems, spread_ty),
+            });
+        }
+
+        // `#[track_caller]` functions receive the location of their caller as an implicit
+        // last argument, which we put into a fresh local.
+        if self.instance.def.requires_caller_location(self.tcx) {
+            let ty = self.cx.translate_ty(self.tcx.caller_location_ty(), self.body.span);
+            self.caller_location = Some(self.fresh_local(ty));
+        }
+
         let init_blk = BasicBlock {
-            statements: rs::always_storage_live_locals(&self.body)
-                .iter()
-                .map(|loc| self.local_name_map[&loc])
-                .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
-                .map(Statement::StorageLive)
-                .collect(),
+            statements,
             terminator: Terminator::Goto(self.bb_name_map[&rs::mir::START_BLOCK]),
         };
         self.blocks.insert(init_bb, init_blk);
@@ -135,9 +181,18 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         let mut args = List::default();
         for i in 0..self.body.arg_count {
             let i = i + 1; // this starts counting with 1, as id 0 is the return value of the function.
+            if self.body.spread_arg.is_some_and(|spread| spread.as_usize() == i) {
+                for local_name in spread_args.iter() {
+                    args.push(local_name);
+                }
+                continue;
+            }
             let local_name = LocalName(Name::from_internal(i as _));
             args.push(local_name);
         }
+        if let Some(caller_location) = self.caller_location {
+            args.push(caller_location);
+        }
 
         let f = Function {
             locals: self.locals,
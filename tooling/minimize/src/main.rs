@@ -21,14 +21,18 @@ extern crate stable_mir;
 mod rs {
     pub use rustc_const_eval::const_eval::mk_eval_cx_for_const_val;
     pub use rustc_const_eval::interpret::{InterpCx, OpTy};
+    pub use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
     pub use rustc_middle::mir::{self, interpret::*, *};
     pub use rustc_middle::span_bug;
+    pub use rustc_middle::ty::print::with_no_visible_paths;
     pub use rustc_middle::ty::*;
     pub use rustc_mir_dataflow::storage::always_storage_live_locals;
+    pub use rustc_span::def_id::DefId;
     pub use rustc_span::source_map::Spanned;
-    pub use rustc_span::{DUMMY_SP, Span, sym};
+    pub use rustc_span::{DUMMY_SP, Span, Symbol, sym};
     pub use rustc_target::abi::{self, Align, FieldIdx, Layout, Size, call::*};
     pub use rustc_target::abi::{FieldsShape, TagEncoding, VariantIdx, Variants};
+    pub use rustc_target::spec::abi::Abi;
 
     pub type CompileTimeInterpCx<'tcx> =
         InterpCx<'tcx, rustc_const_eval::const_eval::CompileTimeMachine<'tcx>>;
@@ -57,13 +61,18 @@ pub use miniutil::BasicMem;
 pub use miniutil::DefaultTarget;
 pub use miniutil::TreeBorrowMem;
 pub use miniutil::build::{self, TypeConv as _, unit_place};
-pub use miniutil::fmt::dump_program;
+pub use miniutil::fmt::{dump_program, fmt_cfg, fmt_data_race, fmt_ill_formed_location};
 pub use miniutil::run::*;
 
 // Get back some `std` items
 pub use std::format;
 pub use std::string::String;
 
+// Defined before the modules so that they can use it.
+macro_rules! show_error {
+    ($($tt:tt)*) => { crate::show_error(&format_args!($($tt)*)) };
+}
+
 mod program;
 use program::*;
 
@@ -87,6 +96,8 @@ use enums::int_from_bits;
 
 mod vtable;
 
+mod simd;
+
 // Imports for `main``
 
 use std::collections::HashMap;
@@ -110,30 +121,48 @@ fn show_error(msg: &impl std::fmt::Display) -> ! {
     std::process::exit(101) // exit code needed to make ui_test happy
 }
 
-macro_rules! show_error {
-    ($($tt:tt)*) => { crate::show_error(&format_args!($($tt)*)) };
-}
-
 fn main() {
     let (minimize_args, rustc_args) = split_args(std::env::args());
     let dump = minimize_args.iter().any(|x| x == "--minimize-dump");
+    let cfg = minimize_args.iter().any(|x| x == "--minimize-cfg");
 
     get_mini(rustc_args, |_tcx, prog| {
         if dump {
             dump_program(prog);
+        } else if cfg {
+            print!("{}", fmt_cfg(prog));
         } else {
-            match run_prog(prog, &minimize_args) {
+            let Some(info) = run_prog(prog, &minimize_args) else {
+                show_error!("step limit exceeded")
+            };
+            match info {
                 // We can't use tcx.dcx().fatal due to <https://github.com/oli-obk/ui_test/issues/226>
-                TerminationInfo::IllFormed(err) =>
+                TerminationInfo::IllFormed(err, location) => {
+                    let location = location
+                        .map(|loc| format!(" (at {})", fmt_ill_formed_location(loc)))
+                        .unwrap_or_default();
                     show_error!(
-                        "program not well-formed (this is a bug in minimize):\n    {}",
+                        "program not well-formed (this is a bug in minimize):\n    {}{location}",
                         err.get_internal()
-                    ),
-                TerminationInfo::MachineStop => { /* silent exit. */ }
+                    )
+                }
+                TerminationInfo::MachineStop(code) =>
+                    if code != Int::ZERO {
+                        // The `Exit` intrinsic only accepts `i32` exit codes.
+                        let code = code.bring_in_bounds(Unsigned, Size::from_bytes_const(4));
+                        std::process::exit(code.try_to_usize().unwrap() as u32 as i32);
+                    },
                 TerminationInfo::Abort(err) => show_error!("Panic: {}", err.get_internal()),
                 TerminationInfo::Ub(err) => show_error!("UB: {}", err.get_internal()),
                 TerminationInfo::Deadlock => show_error!("program dead-locked"),
                 TerminationInfo::MemoryLeak => show_error!("program leaked memory"),
+                TerminationInfo::AllocFailure => show_error!("program ran out of memory"),
+                TerminationInfo::StackOverflow => show_error!("program overflowed its stack"),
+                TerminationInfo::DataRace { first, second } =>
+                    show_error!(
+                        "UB: Data race: {}",
+                        fmt_data_race(first.extract(), second.extract())
+                    ),
             }
         }
     });
@@ -153,11 +182,23 @@ fn split_args(args: Args) -> (Vec<String>, Vec<String>) {
     (minimize_args, rustc_args)
 }
 
-fn run_prog(prog: Program, args: &Vec<String>) -> TerminationInfo {
+/// Run the program, or return `None` if it exceeds the step limit given by `--minimize-steps=N`.
+fn run_prog(prog: Program, args: &Vec<String>) -> Option<TerminationInfo> {
+    let max_steps = args
+        .iter()
+        .find_map(|x| x.strip_prefix("--minimize-steps="))
+        .map(|n| n.parse::<usize>().unwrap_or_else(|_| show_error!("invalid step limit: {n}")));
     if args.iter().any(|x| x == "--minimize-tree-borrows") {
-        run_program::<TreeBorrowMem>(prog)
+        run_prog_with::<TreeBorrowMem>(prog, max_steps)
     } else {
-        run_program::<BasicMem>(prog)
+        run_prog_with::<BasicMem>(prog, max_steps)
+    }
+}
+
+fn run_prog_with<M: Memory>(prog: Program, max_steps: Option<usize>) -> Option<TerminationInfo> {
+    match max_steps {
+        Some(max_steps) => run_program_bounded::<M>(prog, max_steps),
+        None => Some(run_program::<M>(prog)),
     }
 }
 
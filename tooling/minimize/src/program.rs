@@ -78,6 +78,17 @@ impl<'tcx> Ctxt<'tcx> {
             let instance =
                 self.fn_name_map.iter().find(|(_, f)| **f == fn_name).map(|(r, _)| r).unwrap();
 
+            // Naked functions consist of a single `asm!` block, which MiniRust cannot express.
+            if let rs::InstanceKind::Item(def_id) = instance.def {
+                let attrs = self.tcx.codegen_fn_attrs(def_id);
+                if attrs.flags.contains(rs::CodegenFnAttrFlags::NAKED) {
+                    show_error!(
+                        "unsupported: cannot translate naked function `{}`",
+                        self.tcx.def_path_str(def_id)
+                    );
+                }
+            }
+
             let f = FnCtxt::new(*instance, &mut self).translate();
             self.functions.insert(fn_name, f);
         }
@@ -17,6 +17,11 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let l = self.translate_operand_smir(l, span);
                 let r = self.translate_operand_smir(r, span);
 
+                // Floats are translated as their bit pattern, so integer operations would be wrong.
+                if smir::internal(self.tcx, lty_smir).is_floating_point() {
+                    rs::span_bug!(span, "Binary Op {bin_op:?} not supported for type {lty_smir}.");
+                }
+
                 use smir::BinOp::*;
                 match (bin_op, lty) {
                     (Offset, Type::Ptr(_)) => {
@@ -91,6 +96,11 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let ty = self.translate_ty_smir(ty_smir, span);
                 let operand = self.translate_operand_smir(operand, span);
 
+                // Floats are translated as their bit pattern, so integer operations would be wrong.
+                if smir::internal(self.tcx, ty_smir).is_floating_point() {
+                    rs::span_bug!(span, "UnOp {unop:?} called with unsupported type {ty_smir}.");
+                }
+
                 use smir::UnOp::*;
                 match (unop, ty) {
                     (Neg, Type::Int(_)) => build::neg(operand),
@@ -117,6 +127,21 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let rs_ty = smir::internal(self.tcx, ty);
                 let ty = self.translate_ty(rs_ty, span);
 
+                if matches!(null_op, smir::NullOp::SizeOf | smir::NullOp::AlignOf) {
+                    // `compute_size`/`compute_align` use the MiniRust layout of `ty`,
+                    // make sure it agrees with what rustc computed.
+                    let layout = self.rs_layout_of(rs_ty);
+                    let rs_layout = LayoutStrategy::Sized(
+                        translate_size(layout.size()),
+                        translate_align(layout.align().abi),
+                    );
+                    if !layout.is_sized() || ty.layout::<DefaultTarget>() != rs_layout {
+                        show_error!(
+                            "unsupported: MiniRust layout of `{rs_ty}` differs from the rustc layout"
+                        );
+                    }
+                }
+
                 match null_op {
                     smir::NullOp::UbChecks => build::const_bool(self.tcx.sess.ub_checks()),
                     smir::NullOp::SizeOf => build::compute_size(ty, build::unit()),
@@ -169,8 +194,14 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                             panic!()
                         };
                         let variant_ty = rv.ty(&self.locals_smir).unwrap();
-                        let discriminant =
-                            self.discriminant_for_variant_smir(variant_ty, *variant_idx, span);
+                        let discriminant = if self
+                            .has_restricted_valid_range(smir::internal(self.tcx, variant_ty))
+                        {
+                            // The only variant of a struct with a restricted valid range.
+                            Int::ZERO
+                        } else {
+                            self.discriminant_for_variant_smir(variant_ty, *variant_idx, span)
+                        };
                         let ops: List<_> =
                             operands.iter().map(|x| self.translate_operand_smir(x, span)).collect();
 
@@ -357,7 +388,8 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         else {
                             panic!()
                         };
-                        let instance = smir::Instance::resolve(f, &substs_ref).unwrap();
+                        // `#[track_caller]` functions need a shim that supplies the location.
+                        let instance = smir::Instance::resolve_for_fn_ptr(f, &substs_ref).unwrap();
 
                         build::fn_ptr_internal(self.cx.get_fn_name_smir(instance).0.get_internal())
                     }
@@ -421,7 +453,33 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             place.projection.iter().fold((expr, place_ty), |(expr, place_ty), proj| {
                 let this_ty = proj.ty(place_ty).unwrap();
                 let this_expr = match proj {
+                    smir::ProjectionElem::Field(f, _ty)
+                        if smir::internal(self.tcx, place_ty).is_box() =>
+                    {
+                        // We represent `Box` as a pointer, so its fields (the `Unique` pointer and
+                        // the allocator) are accessed by reinterpreting the memory of the box.
+                        let layout = self.rs_layout_of(smir::internal(self.tcx, place_ty));
+                        let offset = layout.fields().offset(*f);
+                        let addr = build::addr_of(expr, build::raw_void_ptr_ty());
+                        let addr = build::ptr_offset(
+                            addr,
+                            build::const_int(offset.bytes()),
+                            build::InBounds::Yes,
+                        );
+                        PlaceExpr::Deref {
+                            operand: GcCow::new(addr),
+                            ty: self.translate_ty_smir(this_ty, span),
+                        }
+                    }
                     smir::ProjectionElem::Field(f, _ty) => {
+                        // Structs with a restricted valid range are represented as single-variant enums.
+                        let expr = if self
+                            .has_restricted_valid_range(smir::internal(self.tcx, place_ty))
+                        {
+                            PlaceExpr::Downcast { root: GcCow::new(expr), discriminant: Int::ZERO }
+                        } else {
+                            expr
+                        };
                         let indirected = GcCow::new(expr);
                         PlaceExpr::Field { root: indirected, field: (*f).into() }
                     }
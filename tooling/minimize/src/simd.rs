@@ -0,0 +1,97 @@
+use crate::*;
+
+// MiniRust has no dedicated SIMD types. A `#[repr(simd)]` type is translated like any other struct,
+// which preserves its size and alignment, and SIMD intrinsics are lowered to one operation per lane.
+
+impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
+    /// Returns the place of lane `lane` of the SIMD vector `place`.
+    /// SIMD vectors are structs with a single array field, so the lanes are the array elements.
+    fn simd_lane(place: PlaceExpr, lane: u64) -> PlaceExpr {
+        build::index(build::field(place, 0), build::const_int(lane as usize))
+    }
+
+    /// Returns the place of a SIMD vector operand.
+    fn simd_operand_place(&mut self, operand: &rs::Spanned<rs::Operand<'tcx>>) -> PlaceExpr {
+        match &operand.node {
+            rs::Operand::Move(place) | rs::Operand::Copy(place) =>
+                self.translate_place(place, operand.span),
+            rs::Operand::Constant(_) => show_error!("unsupported: constant SIMD vectors"),
+        }
+    }
+
+    /// Lowers a lane-wise binary SIMD intrinsic on integer lanes to one assignment per lane.
+    /// `lane_op` computes the value of a result lane from the two argument lanes.
+    fn translate_simd_lanewise(
+        &mut self,
+        args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        span: rs::Span,
+        lane_op: impl Fn(ValueExpr, ValueExpr) -> ValueExpr,
+    ) -> List<Statement> {
+        let ty = args[0].node.ty(&self.body, self.tcx);
+        let (count, elem_ty) = ty.simd_size_and_type(self.tcx);
+        if !elem_ty.is_integral() {
+            show_error!("unsupported: SIMD vectors with non-integer lanes: {ty}");
+        }
+        // rustc already rejects mismatched vectors, but we double-check since we index the lanes.
+        let right_ty = args[1].node.ty(&self.body, self.tcx);
+        let dest_ty = destination.ty(&self.body, self.tcx).ty;
+        if right_ty.simd_size_and_type(self.tcx).0 != count
+            || dest_ty.simd_size_and_type(self.tcx).0 != count
+        {
+            rs::span_bug!(span, "SIMD vectors of mismatched length");
+        }
+
+        let left = self.simd_operand_place(&args[0]);
+        let right = self.simd_operand_place(&args[1]);
+        let destination = self.translate_place(destination, span);
+
+        (0..count)
+            .map(|lane| {
+                let l = build::load(Self::simd_lane(left, lane));
+                let r = build::load(Self::simd_lane(right, lane));
+                Statement::Assign {
+                    destination: Self::simd_lane(destination, lane),
+                    source: lane_op(l, r),
+                }
+            })
+            .collect()
+    }
+
+    /// Lowers `simd_add`, `simd_and`, etc. to the integer operation `op` on each lane.
+    pub fn translate_simd_binop(
+        &mut self,
+        op: IntBinOp,
+        args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        span: rs::Span,
+    ) -> List<Statement> {
+        self.translate_simd_lanewise(args, destination, span, |l, r| {
+            ValueExpr::BinOp { operator: BinOp::Int(op), left: GcCow::new(l), right: GcCow::new(r) }
+        })
+    }
+
+    /// Lowers `simd_eq`: each result lane is all ones if the argument lanes are equal, and zero otherwise.
+    pub fn translate_simd_eq(
+        &mut self,
+        args: &[rs::Spanned<rs::Operand<'tcx>>],
+        destination: &rs::Place<'tcx>,
+        span: rs::Span,
+    ) -> List<Statement> {
+        let dest_ty = destination.ty(&self.body, self.tcx).ty;
+        let (_, mask_ty) = dest_ty.simd_size_and_type(self.tcx);
+        let Type::Int(mask_ty) = self.translate_ty(mask_ty, span) else {
+            show_error!("unsupported: SIMD comparison with non-integer result lanes: {dest_ty}");
+        };
+
+        self.translate_simd_lanewise(args, destination, span, |l, r| {
+            // Turn the `bool` into 0 or 1, then negate it to get 0 or all ones.
+            let bit = build::bool_to_int::<u8>(build::eq(l, r));
+            let int = ValueExpr::UnOp {
+                operator: UnOp::Cast(CastOp::IntToInt(mask_ty)),
+                operand: GcCow::new(bit),
+            };
+            build::neg(int)
+        })
+    }
+}
@@ -1,5 +1,7 @@
 use crate::*;
 
+use crate::rustc_middle::ty::layout::PrimitiveExt;
+
 impl<'tcx> Ctxt<'tcx> {
     pub fn pointee_info_of(&mut self, ty: rs::Ty<'tcx>, span: rs::Span) -> PointeeInfo {
         let layout = self.rs_layout_of(ty);
@@ -60,6 +62,13 @@ impl<'tcx> Ctxt<'tcx> {
                 let sz = rs::abi::Integer::from_uint_ty(&self.tcx, *t).size();
                 Type::Int(IntType { size: translate_size(sz), signed: Signedness::Unsigned })
             }
+            rs::TyKind::Float(t) => {
+                // MiniRust has no floating-point types or operations yet. We represent all float
+                // widths by an unsigned integer of the same size, which has the same validity
+                // invariant, so floats can be moved around but not computed with.
+                let sz = rs::Size::from_bits(t.bit_width());
+                Type::Int(IntType { size: translate_size(sz), signed: Signedness::Unsigned })
+            }
             rs::TyKind::Tuple(ts) => {
                 let layout = self.rs_layout_of(ty);
                 let size = translate_size(layout.size());
@@ -86,7 +95,12 @@ impl<'tcx> Ctxt<'tcx> {
             }
             rs::TyKind::Adt(adt_def, sref) if adt_def.is_struct() => {
                 let (fields, size, align) = self.translate_non_enum_adt(ty, *adt_def, sref, span);
-                build::tuple_ty(&fields.iter().collect::<Vec<_>>(), size, align)
+                let tuple = build::tuple_ty(&fields.iter().collect::<Vec<_>>(), size, align);
+                if self.has_restricted_valid_range(ty) {
+                    self.translate_valid_range_struct(ty, tuple, span)
+                } else {
+                    tuple
+                }
             }
             rs::TyKind::Adt(adt_def, sref) if adt_def.is_union() => {
                 let (fields, size, align) = self.translate_non_enum_adt(ty, *adt_def, sref, span);
@@ -109,6 +123,30 @@ impl<'tcx> Ctxt<'tcx> {
                 let elem = GcCow::new(self.translate_ty(*ty, span));
                 Type::Array { elem, count }
             }
+            rs::TyKind::Closure(_, args) => {
+                // A closure is represented by the tuple of its captured variables.
+                let layout = self.rs_layout_of(ty);
+                let size = translate_size(layout.size());
+                let align = translate_align(layout.align().abi);
+
+                let fields = args
+                    .as_closure()
+                    .upvar_tys()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| {
+                        let t = self.translate_ty(t, span);
+                        let offset = translate_size(layout.fields().offset(i));
+                        (offset, t)
+                    })
+                    .collect::<Vec<_>>();
+
+                build::tuple_ty(&fields, size, align)
+            }
+            rs::TyKind::FnDef(..) => {
+                // A function item is a zero-sized value; calls to it are resolved statically.
+                build::tuple_ty(&[], build::size(0), build::align(1))
+            }
             rs::TyKind::FnPtr(..) => Type::Ptr(PtrType::FnPtr),
             rs::TyKind::Never =>
                 build::enum_ty::<u8>(&[], Discriminator::Invalid, build::size(0), build::align(1)),
@@ -157,6 +195,56 @@ impl<'tcx> Ctxt<'tcx> {
             .collect()
     }
 
+    /// Whether `ty` is a struct like the ones inside `NonZero` and `NonNull` that restricts
+    /// the valid range of its scalar using `#[rustc_layout_scalar_valid_range_*]`.
+    pub fn has_restricted_valid_range(&self, ty: rs::Ty<'tcx>) -> bool {
+        let rs::TyKind::Adt(adt_def, _) = ty.kind() else { return false };
+        adt_def.is_struct()
+            && self.tcx.layout_scalar_valid_range(adt_def.did())
+                != (std::ops::Bound::Unbounded, std::ops::Bound::Unbounded)
+    }
+
+    /// MiniRust types have no validity ranges, so we represent a struct with a restricted
+    /// valid range as an enum with a single variant holding the struct fields.
+    /// The discriminator makes all values outside of the valid range invalid.
+    fn translate_valid_range_struct(
+        &mut self,
+        ty: rs::Ty<'tcx>,
+        tuple: Type,
+        span: rs::Span,
+    ) -> Type {
+        let layout = self.rs_layout_of(ty);
+        // For wide pointers like `NonNull<[T]>`, the range restricts the first component.
+        let scalar = match layout.abi() {
+            rs::abi::Abi::Scalar(scalar) | rs::abi::Abi::ScalarPair(scalar, _) => scalar,
+            _ => rs::span_bug!(span, "struct with restricted valid range is not a scalar: {ty}"),
+        };
+        let Type::Int(value_type) = self.translate_ty(scalar.primitive().to_int_ty(self.tcx), span)
+        else {
+            panic!("scalar has invalid primitive type")
+        };
+
+        let mut children = Map::new();
+        self.insert_invalid_range_branches(scalar, value_type, &mut children);
+        let discriminator = Discriminator::Branch {
+            offset: Offset::ZERO,
+            value_type,
+            fallback: GcCow::new(Discriminator::Known(Int::ZERO)),
+            children,
+        };
+        let variants = [(Int::ZERO, Variant { ty: tuple, tagger: Map::new() })];
+        Type::Enum {
+            variants: variants.into_iter().collect(),
+            discriminator,
+            discriminant_ty: IntType {
+                signed: Signedness::Unsigned,
+                size: Size::from_bytes_const(1),
+            },
+            size: translate_size(layout.size()),
+            align: translate_align(layout.align().abi),
+        }
+    }
+
     fn translate_non_enum_adt(
         &mut self,
         ty: rs::Ty<'tcx>,
@@ -0,0 +1,9 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// `mem::forget` skips the drop of the box, so its allocation is never freed.
+fn main() {
+    let b = Box::new(42u32);
+    print(*b);
+    std::mem::forget(b);
+}
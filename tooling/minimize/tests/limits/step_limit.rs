@@ -0,0 +1,7 @@
+//@ compile-flags: --minimize-steps=1000
+
+// Check that `--minimize-steps` stops a non-terminating program.
+
+fn main() {
+    loop {}
+}
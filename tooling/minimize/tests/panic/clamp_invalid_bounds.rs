@@ -0,0 +1,3 @@
+fn main() {
+    let _ = 5i32.clamp(10, 0);
+}
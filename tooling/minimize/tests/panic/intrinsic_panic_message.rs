@@ -0,0 +1,6 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    panic("custom message");
+}
@@ -0,0 +1,7 @@
+fn pow(x: u32, n: u32) -> u32 {
+    x.pow(n)
+}
+
+fn main() {
+    let _ = pow(2, 32);
+}
@@ -0,0 +1,26 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(align(8))]
+struct Aligned([u8; 16]);
+
+fn main() {
+    let buf = Aligned([0; 16]);
+
+    // Already aligned.
+    let p = &buf.0[0] as *const u8;
+    print(p.align_offset(8));
+
+    // Needs 7 more bytes to reach the next 8-byte boundary.
+    let p = &buf.0[1] as *const u8;
+    print(p.align_offset(8));
+    print((p.wrapping_add(p.align_offset(8)) as usize) % 8);
+
+    // Steps are counted in elements: 3 `u16`s from offset 2.
+    let p = &buf.0[2] as *const u8 as *const u16;
+    print(p.align_offset(8));
+
+    // An odd address can never become 2-aligned in steps of `u16`.
+    let p = &buf.0[1] as *const u8 as *const u16;
+    print(p.align_offset(2) == usize::MAX);
+}
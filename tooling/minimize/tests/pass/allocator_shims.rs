@@ -0,0 +1,32 @@
+// `Vec` and `Box` allocate through these global allocator entry points. They have no MIR,
+// so minimize lowers calls to them to the MiniRust heap intrinsics.
+// `vec.rs` uses them through `Vec`; this calls each of them directly.
+extern crate intrinsics;
+use intrinsics::*;
+
+extern "Rust" {
+    fn __rust_alloc(size: usize, align: usize) -> *mut u8;
+    fn __rust_alloc_zeroed(size: usize, align: usize) -> *mut u8;
+    fn __rust_dealloc(ptr: *mut u8, size: usize, align: usize);
+    fn __rust_realloc(ptr: *mut u8, old_size: usize, align: usize, new_size: usize) -> *mut u8;
+}
+
+fn main() {
+    unsafe {
+        let p = __rust_alloc(8, 4) as *mut u32;
+        *p = 1;
+        *p.add(1) = 2;
+
+        // Grow to hold a third element; the existing ones are kept.
+        let p = __rust_realloc(p as *mut u8, 8, 4, 12) as *mut u32;
+        *p.add(2) = 3;
+        print(*p);
+        print(*p.add(1));
+        print(*p.add(2));
+        __rust_dealloc(p as *mut u8, 12, 4);
+
+        let z = __rust_alloc_zeroed(4, 4) as *mut u32;
+        print(*z);
+        __rust_dealloc(z as *mut u8, 4, 4);
+    }
+}
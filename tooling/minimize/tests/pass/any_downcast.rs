@@ -0,0 +1,24 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::any::Any;
+
+fn main() {
+    let x = 42u32;
+    let a: &dyn Any = &x;
+    print(a.is::<u32>());
+    print(a.is::<i32>());
+    match a.downcast_ref::<u32>() {
+        Some(v) => print(*v),
+        None => print(0),
+    }
+    print(a.downcast_ref::<u64>().is_none());
+
+    let mut y = 7u8;
+    let m: &mut dyn Any = &mut y;
+    if let Some(v) = m.downcast_mut::<u8>() {
+        *v += 1;
+    }
+    print(m.downcast_mut::<u16>().is_none());
+    print(y);
+}
@@ -0,0 +1,18 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+fn get(s: &[u32], i: usize) -> u32 {
+    // Lets the optimizer drop the bounds check below.
+    unsafe { std::hint::assert_unchecked(i < s.len()) };
+    s[i]
+}
+
+fn main() {
+    let a = [1u32, 2, 3];
+    let s = &a[..];
+    print(get(s, 2));
+    unsafe { std::intrinsics::assume(s.len() == 3 && s[0] < s[1]) };
+    print(s[0]);
+}
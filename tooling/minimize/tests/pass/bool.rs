@@ -9,4 +9,7 @@ fn main() {
     else {
         print(-1);
     }
+    if !unsafe { std::mem::transmute::<u8, bool>(1) } {
+        print(-1);
+    }
 }
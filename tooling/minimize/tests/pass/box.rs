@@ -0,0 +1,15 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let b = Box::new(42u32);
+    print(*b);
+
+    let mut arr = Box::new([1u8, 2, 3]);
+    arr[1] = 7;
+    print(arr[1]);
+
+    // Moving the box out and back in keeps the allocation alive.
+    let moved = b;
+    print(*moved + 1);
+}
@@ -0,0 +1,11 @@
+//@ compile-flags: --minimize-cfg
+
+// Check that `--minimize-cfg` prints the control-flow graph instead of running the program.
+
+fn choose(b: bool) -> u32 {
+    if b { 1 } else { 2 }
+}
+
+fn main() {
+    choose(true);
+}
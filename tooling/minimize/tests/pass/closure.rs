@@ -0,0 +1,33 @@
+fn call_twice(mut f: impl FnMut()) {
+    f();
+    f();
+}
+
+fn apply<F: Fn(u32) -> u32>(f: F, x: u32) -> u32 {
+    f(x)
+}
+
+fn consume<F: FnOnce() -> u32>(f: F) -> u32 {
+    f()
+}
+
+fn main() {
+    // Capturing by mutable reference.
+    let mut counter = 0u32;
+    let mut inc = || counter += 1;
+    inc();
+    inc();
+    call_twice(&mut inc);
+    assert!(counter == 4);
+
+    // Capturing by shared reference.
+    let offset = 10u32;
+    let add = |x: u32| x + offset;
+    assert!(add(1) == 11);
+    assert!(apply(add, 5) == 15);
+
+    // Capturing by value.
+    let y = 7u32;
+    let f = move || y * 2;
+    assert!(consume(f) == 14);
+}
@@ -0,0 +1,20 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::ptr;
+
+// `write_bytes` and `copy_nonoverlapping` are lowered to loops over the bytes.
+fn main() {
+    let mut a = [0x0102_0304u32; 3];
+    unsafe { ptr::write_bytes(a.as_mut_ptr().add(1), 0xff, 2) };
+    print(a[0]);
+    print(a[1]);
+    print(a[2]);
+
+    // Copying a pointer byte by byte keeps its provenance.
+    let x = 42u32;
+    let src = [&x];
+    let mut dst = [&0u32];
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), 1) };
+    print(*dst[0]);
+}
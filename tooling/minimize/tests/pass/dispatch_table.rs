@@ -0,0 +1,43 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn zero() -> u32 {
+    0
+}
+fn one() -> u32 {
+    1
+}
+fn two() -> u32 {
+    2
+}
+
+static TABLE: [fn() -> u32; 3] = [zero, one, two];
+const CONST_TABLE: &[fn() -> u32] = &[two, one, zero];
+
+trait Name {
+    fn id(&self) -> u32;
+}
+struct A;
+struct B;
+impl Name for A {
+    fn id(&self) -> u32 {
+        10
+    }
+}
+impl Name for B {
+    fn id(&self) -> u32 {
+        20
+    }
+}
+
+static OBJECTS: [&(dyn Name + Sync); 2] = [&A, &B];
+
+fn main() {
+    for i in 0..3 {
+        print(TABLE[i]());
+    }
+    print(CONST_TABLE[0]());
+    for i in 0..2 {
+        print(OBJECTS[i].id());
+    }
+}
@@ -0,0 +1,75 @@
+//! Checks enums where the niche has to encode more than one variant.
+use std::num::NonZeroU8;
+
+/// `NonZeroU8` only has a single niche value, so this needs a separate tag.
+enum NonZeroEnum {
+    A(NonZeroU8),
+    B,
+    C,
+}
+
+/// `B` and `C` are stored in the niche of the `bool`.
+enum BoolEnum {
+    A(bool),
+    B,
+    C,
+}
+
+/// Both `A` and `B` carry data, `C` and `D` go into the niche of `A`'s `bool`.
+enum MultiDataful {
+    A(bool, u8),
+    B(u8),
+    C,
+    D,
+}
+
+fn non_zero(x: u8) -> NonZeroU8 {
+    match NonZeroU8::new(x) {
+        Some(v) => v,
+        None => unreachable!(),
+    }
+}
+
+fn discr_non_zero(e: NonZeroEnum) -> u8 {
+    match e {
+        NonZeroEnum::A(x) => x.get(),
+        NonZeroEnum::B => 0,
+        NonZeroEnum::C => 1,
+    }
+}
+
+fn discr_bool(e: BoolEnum) -> u8 {
+    match e {
+        BoolEnum::A(false) => 0,
+        BoolEnum::A(true) => 1,
+        BoolEnum::B => 2,
+        BoolEnum::C => 3,
+    }
+}
+
+fn discr_multi(e: MultiDataful) -> u8 {
+    match e {
+        MultiDataful::A(b, x) => x + b as u8,
+        MultiDataful::B(x) => x,
+        MultiDataful::C => 100,
+        MultiDataful::D => 200,
+    }
+}
+
+fn main() {
+    assert!(discr_non_zero(NonZeroEnum::A(non_zero(42))) == 42);
+    assert!(discr_non_zero(NonZeroEnum::B) == 0);
+    assert!(discr_non_zero(NonZeroEnum::C) == 1);
+
+    assert!(std::mem::size_of::<BoolEnum>() == 1);
+    assert!(discr_bool(BoolEnum::A(false)) == 0);
+    assert!(discr_bool(BoolEnum::A(true)) == 1);
+    assert!(discr_bool(BoolEnum::B) == 2);
+    assert!(discr_bool(BoolEnum::C) == 3);
+
+    assert!(std::mem::size_of::<MultiDataful>() == 2);
+    assert!(discr_multi(MultiDataful::A(true, 10)) == 11);
+    assert!(discr_multi(MultiDataful::B(7)) == 7);
+    assert!(discr_multi(MultiDataful::C) == 100);
+    assert!(discr_multi(MultiDataful::D) == 200);
+}
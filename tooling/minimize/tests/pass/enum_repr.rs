@@ -0,0 +1,60 @@
+#[repr(u16)]
+#[derive(Clone, Copy)]
+enum E {
+    A = 5,
+    B = 300,
+}
+
+#[repr(C, u16)]
+enum WithData {
+    A(u8) = 5,
+    B(u32) = 300,
+}
+
+#[repr(C)]
+enum CEnum {
+    A(u8),
+    B(u16),
+}
+
+fn tag_of<T>(x: &T) -> u16 {
+    unsafe { *(x as *const T as *const u16) }
+}
+
+fn main() {
+    assert!(E::A as u16 == 5);
+    assert!(E::B as u16 == 300);
+    assert!(tag_of(&E::A) == 5);
+    assert!(tag_of(&E::B) == 300);
+    assert!(std::mem::size_of::<E>() == 2);
+
+    // The tag comes first, followed by the payload at its alignment.
+    let a = WithData::A(42);
+    let b = WithData::B(1 << 20);
+    assert!(tag_of(&a) == 5);
+    assert!(tag_of(&b) == 300);
+    assert!(unsafe { *(&a as *const WithData as *const u8).add(4) } == 42);
+    assert!(unsafe { *(&b as *const WithData as *const u32).add(1) } == 1 << 20);
+    match a {
+        WithData::A(x) => assert!(x == 42),
+        WithData::B(_) => unreachable!(),
+    }
+    match b {
+        WithData::A(_) => unreachable!(),
+        WithData::B(x) => assert!(x == 1 << 20),
+    }
+
+    // `repr(C)` uses a C-sized `int` tag.
+    let c = CEnum::A(3);
+    let d = CEnum::B(7);
+    assert!(unsafe { *(&c as *const CEnum as *const u32) } == 0);
+    assert!(unsafe { *(&d as *const CEnum as *const u32) } == 1);
+    match c {
+        CEnum::A(x) => assert!(x == 3),
+        CEnum::B(_) => unreachable!(),
+    }
+    match d {
+        CEnum::A(_) => unreachable!(),
+        CEnum::B(x) => assert!(x == 7),
+    }
+}
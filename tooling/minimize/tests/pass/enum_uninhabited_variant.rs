@@ -0,0 +1,32 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::convert::Infallible;
+
+fn make_ok(x: u32) -> Result<u32, Infallible> {
+    Ok(x)
+}
+
+fn make_err(x: u32) -> Result<Infallible, u32> {
+    Err(x)
+}
+
+fn get(r: Result<u32, Infallible>) -> u32 {
+    match r {
+        Ok(x) => x,
+        Err(e) => match e {},
+    }
+}
+
+fn main() {
+    print(get(make_ok(42)));
+
+    let Ok(x) = make_ok(7);
+    print(x);
+
+    let Err(y) = make_err(13);
+    print(y);
+
+    let o: Option<Infallible> = None;
+    print(o.is_none());
+}
@@ -0,0 +1,24 @@
+#![feature(f16, f128)]
+
+fn copy<T: Copy>(x: &T) -> T {
+    *x
+}
+
+fn main() {
+    // Floats can only be moved around, so we check their bit patterns.
+    let x: f16 = 1.5;
+    let y = copy(&x);
+    assert!(unsafe { std::mem::transmute::<f16, u16>(y) } == 0x3e00);
+
+    let mut arr = [0.0f16; 2];
+    arr[1] = x;
+    assert!(unsafe { std::mem::transmute::<f16, u16>(arr[1]) } == 0x3e00);
+
+    let z: f32 = 1.0;
+    assert!(unsafe { std::mem::transmute::<f32, u32>(copy(&z)) } == 0x3f80_0000);
+    let w: f64 = -2.0;
+    assert!(unsafe { std::mem::transmute::<f64, u64>(copy(&w)) } == 0xc000_0000_0000_0000);
+    let q: f128 = 1.0;
+    assert!(unsafe { std::mem::transmute::<f128, u128>(copy(&q)) } == 0x3fff << 112);
+    assert!(std::mem::size_of::<f128>() == 16);
+}
@@ -0,0 +1,42 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn double(x: u32) -> u32 {
+    2 * x
+}
+
+fn apply(f: impl Fn(u32) -> u32, x: u32) -> u32 {
+    f(x)
+}
+
+fn main() {
+    let mut sum = 0u32;
+    for i in 0..10 {
+        sum += i;
+    }
+    print(sum);
+
+    let mut sum = 0i64;
+    for i in (1..=5).rev() {
+        sum += i;
+    }
+    print(sum);
+
+    let mut count = 0;
+    for _ in 0..0 {
+        count += 1;
+    }
+    print(count);
+
+    // `zip` passes the function item `Ord::cmp` to `min_by`.
+    let a = [1u8, 2, 3];
+    let b = [4u8, 5, 6];
+    let mut dot = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        dot += x * y;
+    }
+    print(dot);
+
+    // Function items can be passed where a closure is expected.
+    print(apply(double, 21));
+}
@@ -0,0 +1,16 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::cmp::{max, min};
+
+fn main() {
+    print(min(3u32, 7));
+    print(max(3u32, 7));
+    print(min(-5i8, 2));
+    print(max(-5i8, -9));
+    print(10i64.min(-10));
+    print(10u16.max(20));
+    print(15i32.clamp(0, 10));
+    print((-3i32).clamp(0, 10));
+    print(4usize.clamp(0, 10));
+}
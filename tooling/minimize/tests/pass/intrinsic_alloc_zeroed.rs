@@ -0,0 +1,11 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    unsafe {
+        let ptr = allocate_zeroed(8, 4) as *mut u32;
+        print(*ptr);
+        print(*ptr.add(1));
+        deallocate(ptr as *mut u8, 8, 4);
+    }
+}
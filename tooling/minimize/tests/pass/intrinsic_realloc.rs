@@ -0,0 +1,22 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    unsafe {
+        let ptr = allocate(4, 2) as *mut u16;
+        *ptr = 7;
+        *ptr.add(1) = 8;
+
+        // Grow the allocation: the old contents are preserved.
+        let ptr = reallocate(ptr as *mut u8, 4, 2, 8) as *mut u16;
+        *ptr.add(3) = 10;
+        print(*ptr);
+        print(*ptr.add(1));
+        print(*ptr.add(3));
+
+        // Shrink it again.
+        let ptr = reallocate(ptr as *mut u8, 8, 2, 2) as *mut u16;
+        print(*ptr);
+        deallocate(ptr as *mut u8, 2, 2);
+    }
+}
@@ -0,0 +1,28 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn foo() -> u32 {
+    print(1);
+    42
+}
+
+fn unit() {
+    print(2);
+}
+
+struct S {
+    x: u32,
+}
+
+fn make() -> S {
+    print(3);
+    S { x: 0 }
+}
+
+fn main() {
+    let _ = foo();
+    let _ = unit();
+    let _ = make().x;
+    let _ = (foo(), foo());
+    print(4);
+}
@@ -0,0 +1,35 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Other(u8),
+}
+
+// Exhaustive: the `SwitchInt` fallback is unreachable.
+fn total(c: Color) -> u8 {
+    match c {
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Blue => 3,
+        Color::Other(x) => x,
+    }
+}
+
+// Non-exhaustive: the `SwitchInt` fallback jumps to the wildcard arm.
+fn partial(c: Color) -> u8 {
+    match c {
+        Color::Green => 2,
+        _ => 0,
+    }
+}
+
+fn main() {
+    for c in [Color::Red, Color::Green, Color::Blue, Color::Other(42)] {
+        print(total(c));
+        print(partial(c));
+    }
+}
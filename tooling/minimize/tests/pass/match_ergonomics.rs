@@ -0,0 +1,41 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// Default binding modes: `o` is auto-dereferenced and `x` binds by reference.
+fn get(o: &Option<u32>) -> u32 {
+    match o {
+        Some(x) => *x,
+        None => 0,
+    }
+}
+
+// The same match, spelled out explicitly.
+fn get_explicit(o: &Option<u32>) -> u32 {
+    match o {
+        &Some(ref x) => *x,
+        &None => 0,
+    }
+}
+
+// Binding by mutable reference through a `&mut Option`.
+fn bump(o: &mut Option<u32>) {
+    if let Some(x) = o {
+        *x += 1;
+    }
+}
+
+fn main() {
+    let mut a = Some(41u32);
+    let b: Option<u32> = None;
+    print(get(&a));
+    print(get(&b));
+    print(get_explicit(&a));
+    bump(&mut a);
+    print(get_explicit(&a));
+
+    // Two levels of auto-deref.
+    let nested = &&Some(7u32);
+    if let Some(x) = nested {
+        print(*x);
+    }
+}
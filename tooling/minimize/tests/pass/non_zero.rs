@@ -0,0 +1,21 @@
+use std::num::NonZeroU8;
+use std::ptr::NonNull;
+
+fn main() {
+    let x = 42u8;
+    let nz = unsafe { *(&x as *const u8 as *const NonZeroU8) };
+    assert!(nz.get() == 42);
+
+    match NonZeroU8::new(7) {
+        Some(v) => assert!(v.get() == 7),
+        None => unreachable!(),
+    }
+    assert!(NonZeroU8::new(0).is_none());
+
+    let mut y = 5u8;
+    match NonNull::new(&mut y as *mut u8) {
+        Some(p) => assert!(unsafe { *p.as_ptr() } == 5),
+        None => unreachable!(),
+    }
+    assert!(NonNull::new(std::ptr::null_mut::<u8>()).is_none());
+}
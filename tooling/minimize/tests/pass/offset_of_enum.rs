@@ -0,0 +1,28 @@
+#![feature(offset_of_enum)]
+use std::mem;
+
+#[repr(u8)]
+#[allow(dead_code)]
+enum Primitive {
+    A(u8, u32),
+    B { x: u16 },
+}
+
+#[repr(C, u8)]
+#[allow(dead_code)]
+enum Tagged {
+    A(u8, u32),
+    B { x: u16 },
+}
+
+fn main() {
+    // With a primitive representation, each variant is a `repr(C)` struct starting with the tag.
+    assert!(1 == mem::offset_of!(Primitive, A.0));
+    assert!(4 == mem::offset_of!(Primitive, A.1));
+    assert!(2 == mem::offset_of!(Primitive, B.x));
+
+    // With `repr(C, u8)`, the variants are in a union after the tag.
+    assert!(4 == mem::offset_of!(Tagged, A.0));
+    assert!(8 == mem::offset_of!(Tagged, A.1));
+    assert!(4 == mem::offset_of!(Tagged, B.x));
+}
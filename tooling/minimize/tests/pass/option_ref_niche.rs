@@ -0,0 +1,34 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::{size_of, transmute};
+
+fn describe(o: Option<&u32>) -> u32 {
+    match o {
+        Some(x) => *x,
+        None => 0,
+    }
+}
+
+fn main() {
+    let x = 7u32;
+    let some = Some(&x);
+    let none: Option<&u32> = None;
+
+    // The null niche means no separate tag is needed.
+    print(size_of::<Option<&u32>>() == size_of::<&u32>());
+
+    print(some.is_some());
+    print(some.is_none());
+    print(none.is_some());
+    print(none.is_none());
+    print(describe(some));
+    print(describe(none));
+
+    // `None` is represented by the null pointer.
+    let raw: *const u32 = unsafe { transmute(none) };
+    print(raw.is_null());
+    // And `Some(&x)` by the pointer to `x`.
+    let raw: *const u32 = unsafe { transmute(some) };
+    print(raw == &x as *const u32);
+}
@@ -0,0 +1,25 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::cmp::Ordering;
+
+fn classify(o: Ordering) -> i32 {
+    match o {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+fn main() {
+    print(classify(1u32.cmp(&2)));
+    print(classify(2u32.cmp(&2)));
+    print(classify(3u32.cmp(&2)));
+    print(classify((-5i64).cmp(&3)));
+    print(classify(std::intrinsics::three_way_compare(7i8, -7)));
+    print(classify(std::intrinsics::three_way_compare(0u8, 0)));
+    print(2u32.cmp(&1) as i8);
+    print(1u32.cmp(&2) as i8);
+}
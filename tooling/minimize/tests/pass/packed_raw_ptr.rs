@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+// Make sure `b` is misaligned for `u32`.
+#[repr(C, align(4))]
+struct Wrapper(Packed);
+
+fn main() {
+    let mut w = Wrapper(Packed { a: 1, b: 42 });
+    // Forming raw pointers to the misaligned field is fine.
+    let r = &raw const w.0.b;
+    let m = &raw mut w.0.b;
+    print(r as usize % 4);
+    // Accessing it at a lower alignment is fine, too.
+    unsafe { *(m as *mut u8) = 43 };
+    let b = w.0.b;
+    print(b);
+    print(w.0.a);
+}
@@ -0,0 +1,21 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::marker::{PhantomData, PhantomPinned};
+use std::mem::{align_of, size_of};
+
+// `PhantomData` and `PhantomPinned` are zero-sized and do not affect the layout.
+struct S {
+    x: u32,
+    _p: PhantomData<*mut u8>,
+    _q: PhantomPinned,
+}
+
+fn main() {
+    let s = S { x: 7, _p: PhantomData, _q: PhantomPinned };
+    let r = &s;
+    print(r.x);
+    print(size_of::<S>());
+    print(align_of::<S>());
+    print(size_of::<PhantomData<[u64; 8]>>());
+}
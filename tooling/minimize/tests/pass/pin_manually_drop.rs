@@ -0,0 +1,51 @@
+use std::marker::PhantomPinned;
+use std::mem::ManuallyDrop;
+use std::pin::Pin;
+
+struct Counter {
+    count: u32,
+}
+
+fn bump(mut c: Pin<&mut Counter>) {
+    // `Counter` is `Unpin`, so we can get a mutable reference back out.
+    let c: &mut Counter = &mut *c;
+    c.count += 1;
+}
+
+struct SelfRef {
+    val: u32,
+    _pin: PhantomPinned,
+}
+
+fn set(p: Pin<&mut SelfRef>, val: u32) {
+    // `SelfRef` is `!Unpin`, so this needs `unsafe`.
+    let r = unsafe { p.get_unchecked_mut() };
+    r.val = val;
+}
+
+fn main() {
+    let mut c = Counter { count: 0 };
+    let mut pinned = Pin::new(&mut c);
+    bump(pinned.as_mut());
+    pinned.count += 1;
+    bump(pinned);
+    assert!(c.count == 3);
+
+    let mut x = 5u32;
+    let mut pinned = Pin::new(&mut x);
+    *pinned = 10;
+    assert!(*pinned == 10);
+    assert!(x == 10);
+
+    let mut s = SelfRef { val: 0, _pin: PhantomPinned };
+    let mut pinned = unsafe { Pin::new_unchecked(&mut s) };
+    set(pinned.as_mut(), 42);
+    assert!(pinned.val == 42);
+
+    let mut m = ManuallyDrop::new(Counter { count: 7 });
+    m.count *= 2;
+    assert!(m.count == 14);
+    let inner = ManuallyDrop::into_inner(m);
+    assert!(inner.count == 14);
+    assert!(std::mem::size_of::<ManuallyDrop<u64>>() == 8);
+}
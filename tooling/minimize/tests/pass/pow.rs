@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    print(2u32.pow(10));
+    print((-3i32).pow(3));
+    print(7u8.pow(0));
+    print(2u64.checked_pow(64).is_none());
+    print(2i32.wrapping_pow(31));
+}
@@ -0,0 +1,17 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::ptr_guaranteed_cmp;
+
+fn main() {
+    let x = 0u32;
+    let y = 1u32;
+    let px = &x as *const u32;
+    let py = &y as *const u32;
+    // A pointer is guaranteed equal to itself.
+    print(ptr_guaranteed_cmp(px, px));
+    // Pointers to distinct allocations are guaranteed unequal at runtime.
+    print(ptr_guaranteed_cmp(px, py));
+}
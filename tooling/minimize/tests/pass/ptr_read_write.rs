@@ -0,0 +1,13 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = 42u32;
+    let y = unsafe { std::ptr::read(&x) };
+    print(y);
+
+    let mut z = (0u8, 0u16);
+    unsafe { std::ptr::write(&mut z, (1, 2)) };
+    print(z.0);
+    print(z.1);
+}
@@ -0,0 +1,42 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::offset_of;
+
+#[repr(C)]
+struct C {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+// rustc is free to reorder these fields.
+struct R {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+fn main() {
+    // `repr(C)` lays out the fields in declaration order.
+    print(offset_of!(C, a));
+    print(offset_of!(C, b));
+    print(offset_of!(C, c));
+    print(std::mem::size_of::<C>());
+
+    // Whatever order rustc picks, field accesses and raw accesses at `offset_of!` must agree.
+    let c = C { a: 1, b: 2, c: 3 };
+    let r = R { a: 4, b: 5, c: 6 };
+    print(c.a as u32 + c.b + c.c as u32);
+    print(r.a as u32 + r.b + r.c as u32);
+    let pc = &c as *const C as *const u8;
+    let pr = &r as *const R as *const u8;
+    unsafe {
+        print(*pc.add(offset_of!(C, a)));
+        print(*(pc.add(offset_of!(C, b)) as *const u32));
+        print(*(pc.add(offset_of!(C, c)) as *const u16));
+        print(*pr.add(offset_of!(R, a)));
+        print(*(pr.add(offset_of!(R, b)) as *const u32));
+        print(*(pr.add(offset_of!(R, c)) as *const u16));
+    }
+}
@@ -0,0 +1,19 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::select_unpredictable;
+
+fn select(b: bool) -> (u8, u32) {
+    select_unpredictable(b, (1, 10), (2, 20))
+}
+
+fn main() {
+    let (a, b) = select(true);
+    print(a);
+    print(b);
+    let (a, b) = select(false);
+    print(a);
+    print(b);
+}
@@ -0,0 +1,23 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn shl_u8(x: u64, s: u8) -> u64 {
+    x.wrapping_shl(s as u32)
+}
+
+fn main() {
+    let x: u64 = 0x0123_4567_89ab_cdef;
+    let a: u8 = 4;
+    let b: i32 = 60;
+    let c: u16 = 1;
+    print(x << a);
+    print(x >> a);
+    print(x << b);
+    print(x >> b);
+    print(1u64 << c);
+    print(-8i64 >> a);
+    print(-8i64 >> 1i32);
+    print(shl_u8(1, 70));
+    // The wrapping shifts take the amount modulo the bit width.
+    print(x.wrapping_shr(68));
+}
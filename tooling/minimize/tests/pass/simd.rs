@@ -0,0 +1,38 @@
+#![feature(repr_simd, core_intrinsics)]
+#![allow(internal_features)]
+use std::intrinsics::simd::{simd_add, simd_mul};
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct U32x4([u32; 4]);
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct I8x4([i8; 4]);
+
+fn main() {
+    let a = U32x4([1, 2, 3, 4]);
+    let b = U32x4([10, 20, 30, 40]);
+    assert!(std::mem::align_of::<U32x4>() == 16);
+
+    let sum: U32x4 = unsafe { simd_add(a, b) };
+    assert!(sum.0[0] == 11);
+    assert!(sum.0[1] == 22);
+    assert!(sum.0[2] == 33);
+    assert!(sum.0[3] == 44);
+
+    let prod: U32x4 = unsafe { simd_mul(a, b) };
+    assert!(prod.0[0] == 10);
+    assert!(prod.0[1] == 40);
+    assert!(prod.0[2] == 90);
+    assert!(prod.0[3] == 160);
+
+    // Lanes wrap around on overflow.
+    let c = I8x4([i8::MAX, -1, 0, i8::MIN]);
+    let d = I8x4([1, 1, -5, -1]);
+    let sum: I8x4 = unsafe { simd_add(c, d) };
+    assert!(sum.0[0] == i8::MIN);
+    assert!(sum.0[1] == 0);
+    assert!(sum.0[2] == -5);
+    assert!(sum.0[3] == i8::MAX);
+}
@@ -0,0 +1,47 @@
+#![feature(repr_simd, core_intrinsics)]
+#![allow(internal_features)]
+use std::intrinsics::simd::*;
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct U32x4([u32; 4]);
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct I32x4([i32; 4]);
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct I16x4([i16; 4]);
+
+fn check(v: U32x4, expected: [u32; 4]) {
+    assert!(v.0[0] == expected[0]);
+    assert!(v.0[1] == expected[1]);
+    assert!(v.0[2] == expected[2]);
+    assert!(v.0[3] == expected[3]);
+}
+
+fn main() {
+    let a = U32x4([0b1100, 7, 0, u32::MAX]);
+    let b = U32x4([0b1010, 7, 1, 1]);
+
+    check(unsafe { simd_sub(a, b) }, [2, 0, u32::MAX, u32::MAX - 1]);
+    check(unsafe { simd_and(a, b) }, [0b1000, 7, 0, 1]);
+    check(unsafe { simd_or(a, b) }, [0b1110, 7, 1, u32::MAX]);
+    check(unsafe { simd_xor(a, b) }, [0b0110, 0, 1, u32::MAX - 1]);
+
+    let eq: I32x4 = unsafe { simd_eq(a, b) };
+    assert!(eq.0[0] == 0);
+    assert!(eq.0[1] == -1);
+    assert!(eq.0[2] == 0);
+    assert!(eq.0[3] == 0);
+
+    // Comparing signed lanes.
+    let c = I16x4([1, 2, 3, 4]);
+    let d = I16x4([1, 0, 3, 0]);
+    let eq: I16x4 = unsafe { simd_eq(c, d) };
+    assert!(eq.0[0] == -1);
+    assert!(eq.0[1] == 0);
+    assert!(eq.0[2] == -1);
+    assert!(eq.0[3] == 0);
+}
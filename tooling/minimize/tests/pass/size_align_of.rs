@@ -0,0 +1,22 @@
+use std::mem::{align_of, size_of};
+
+#[allow(dead_code)]
+enum E {
+    A,
+    B(u8),
+    C(u32),
+}
+
+fn main() {
+    assert!(size_of::<u8>() == 1);
+    assert!(size_of::<i128>() == 16);
+    assert!(align_of::<u64>() == 8);
+    assert!(size_of::<(u8, u32)>() == 8);
+    assert!(align_of::<(u8, u16)>() == 2);
+    assert!(size_of::<E>() == 8);
+    assert!(align_of::<E>() == 4);
+    assert!(size_of::<Option<&u8>>() == 8);
+    assert!(size_of::<[u16; 5]>() == 10);
+    assert!(size_of::<[E; 3]>() == 24);
+    assert!(size_of::<()>() == 0);
+}
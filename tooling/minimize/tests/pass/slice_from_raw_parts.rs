@@ -0,0 +1,23 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::ptr::NonNull;
+use std::slice;
+
+fn main() {
+    let a = [1u32, 2, 3, 4];
+    let s = unsafe { slice::from_raw_parts(a.as_ptr().add(1), 2) };
+    print(s.len());
+    print(s[0] + s[1]);
+
+    let mut b = [5u16, 6, 7];
+    let m = unsafe { slice::from_raw_parts_mut(b.as_mut_ptr(), 3) };
+    m[2] = 70;
+    print(m.len());
+    print(b[2]);
+
+    // An empty slice may use a dangling but aligned pointer.
+    let e: &[u64] = unsafe { slice::from_raw_parts(NonNull::<u64>::dangling().as_ptr(), 0) };
+    print(e.len());
+    print(e.is_empty());
+}
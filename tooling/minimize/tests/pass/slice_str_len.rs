@@ -0,0 +1,20 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn slice_len(s: &[u32]) -> usize {
+    s.len()
+}
+
+fn str_len(s: &str) -> usize {
+    s.len()
+}
+
+fn main() {
+    let arr = [1u32, 2, 3, 4, 5];
+    print(slice_len(&arr));
+    print(slice_len(&arr[1..3]));
+    print(slice_len(&[]));
+    print(str_len("hello"));
+    print(str_len(""));
+    print("grüße".len());
+}
@@ -0,0 +1,23 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[no_mangle]
+static ANSWER: u32 = 42;
+
+#[used]
+static USED: [u8; 3] = [1, 2, 3];
+
+#[no_mangle]
+static mut COUNTER: u32 = 0;
+
+fn main() {
+    let p: *const u32 = &ANSWER;
+    print(unsafe { *p });
+    let q = &USED;
+    print(q[2]);
+    unsafe {
+        let c = &raw mut COUNTER;
+        *c += 5;
+        print(*c);
+    }
+}
@@ -0,0 +1,17 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let arr = [10u32, 11, 12, 13, 14];
+    let s = &arr[1..3];
+    print(s.len());
+    print(s[0]);
+    print(s[1]);
+    let t = &s[1..];
+    print(t.len());
+    print(t[0]);
+
+    let u = &"hello"[1..4];
+    print(u.len());
+    print(u.as_bytes()[0]);
+}
@@ -0,0 +1,14 @@
+fn main() {
+    let mut a = [1u8; 16];
+    let mut b = [2u8; 16];
+    a[15] = 3;
+    std::mem::swap(&mut a, &mut b);
+    assert!(a[0] == 2 && a[15] == 2);
+    assert!(b[0] == 1 && b[15] == 3);
+
+    let mut x = (1u32, 2u64);
+    let mut y = (3u32, 4u64);
+    std::mem::swap(&mut x, &mut y);
+    assert!(x.0 == 3 && x.1 == 4);
+    assert!(y.0 == 1 && y.1 == 2);
+}
@@ -0,0 +1,39 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(i32)]
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+enum E {
+    A = -1,
+    B = -100,
+    C = 5,
+}
+
+fn classify_i8(x: i8) -> u8 {
+    match x {
+        -1 => 1,
+        -128 => 2,
+        127 => 3,
+        _ => 0,
+    }
+}
+
+fn classify_e(e: E) -> u8 {
+    match e {
+        E::A => 1,
+        E::B => 2,
+        E::C => 3,
+    }
+}
+
+fn main() {
+    print(classify_i8(-1));
+    print(classify_i8(-128));
+    print(classify_i8(127));
+    print(classify_i8(1));
+    print(classify_e(E::A));
+    print(classify_e(E::B));
+    print(classify_e(E::C));
+    print(E::B as i32);
+}
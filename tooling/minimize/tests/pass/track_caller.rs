@@ -0,0 +1,34 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::panic::Location;
+
+#[track_caller]
+fn location() -> &'static Location<'static> {
+    Location::caller()
+}
+
+// Nested `#[track_caller]` functions forward the location of their caller.
+#[track_caller]
+fn nested_location() -> &'static Location<'static> {
+    location()
+}
+
+fn main() {
+    let loc = location();
+    print(loc.line());
+    print(loc.column());
+
+    let loc = nested_location();
+    print(loc.line());
+    print(loc.column());
+
+    // Outside of a `#[track_caller]` function we get our own location.
+    let loc = Location::caller();
+    print(loc.line());
+    print(loc.column());
+
+    // Through a function pointer, the reify shim reports the function definition.
+    let f: fn() -> &'static Location<'static> = location;
+    print(f().line());
+}
@@ -0,0 +1,20 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::transmute;
+
+fn main() {
+    let a = [1u8, 2, 3];
+
+    // Wide to wide pointer: the metadata is kept.
+    let s: &[u8] = &a;
+    let raw: *const [u8] = unsafe { transmute(s) };
+    let s: &[u8] = unsafe { &*raw };
+    print(s.len());
+    print(s[2]);
+
+    // Thin to thin pointer.
+    let x = 42u32;
+    let p: *const u32 = unsafe { transmute(&x) };
+    print(unsafe { *p });
+}
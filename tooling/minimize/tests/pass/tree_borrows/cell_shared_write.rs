@@ -0,0 +1,32 @@
+//@ compile-flags: --minimize-tree-borrows
+
+// Check that writing to a `Cell` through a shared reference is allowed,
+// also when the cell is nested inside other structs.
+
+use std::cell::Cell;
+
+struct Inner {
+    n: u32,
+    c: Cell<u32>,
+}
+
+struct Outer {
+    m: u8,
+    inner: Inner,
+}
+
+fn set(c: &Cell<u32>, val: u32) {
+    c.set(val);
+}
+
+fn main() {
+    let o = Outer { m: 1, inner: Inner { n: 2, c: Cell::new(3) } };
+    let r = &o; // not `Freeze`, so retagging is a NOP
+    r.inner.c.set(4);
+    assert!(o.inner.c.get() == 4);
+
+    let inner = &r.inner;
+    set(&inner.c, 5);
+    assert!(r.inner.c.get() == 5);
+    assert!(o.m == 1 && o.inner.n == 2);
+}
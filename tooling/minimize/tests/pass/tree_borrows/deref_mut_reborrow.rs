@@ -0,0 +1,35 @@
+//@ compile-flags: --minimize-tree-borrows
+
+// Check that reborrowing through a user-defined `Deref`/`DerefMut` is accepted.
+
+use std::ops::{Deref, DerefMut};
+
+struct Wrapper {
+    val: u32,
+}
+
+impl Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &u32 {
+        &self.val
+    }
+}
+
+impl DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut u32 {
+        &mut self.val
+    }
+}
+
+fn main() {
+    let mut w = Wrapper { val: 1 };
+    let r: &mut u32 = &mut *w;
+    *r += 1;
+    let r2 = &mut *r;
+    *r2 += 1;
+    *r += 1;
+    let s: &u32 = &*w;
+    assert!(*s == 4);
+    *w += 1;
+    assert!(w.val == 5);
+}
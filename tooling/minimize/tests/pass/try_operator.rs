@@ -0,0 +1,32 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn parse(x: i32) -> Result<i32, i32> {
+    if x >= 0 { Ok(x * 2) } else { Err(-x) }
+}
+
+fn add_parsed(a: i32, b: i32) -> Result<i32, i32> {
+    let a = parse(a)?;
+    let b = parse(b)?;
+    Ok(a + b)
+}
+
+fn first_some(x: Option<i32>) -> Option<i32> {
+    let x = x?;
+    Some(x + 1)
+}
+
+fn print_result(r: Result<i32, i32>) {
+    match r {
+        Ok(v) => print(v),
+        Err(e) => print(-1000 - e),
+    }
+}
+
+fn main() {
+    print_result(add_parsed(1, 2));
+    print_result(add_parsed(-3, 2));
+    print_result(add_parsed(1, -4));
+    print(first_some(Some(5)).unwrap_or(0));
+    print(first_some(None).unwrap_or(0));
+}
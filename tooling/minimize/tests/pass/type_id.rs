@@ -0,0 +1,13 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+use std::any::type_name;
+use std::intrinsics::type_id;
+
+fn main() {
+    assert!(type_id::<u8>() == type_id::<u8>());
+    assert!(type_id::<u8>() != type_id::<i8>());
+    assert!(type_id::<&u8>() != type_id::<u8>());
+
+    assert!(type_name::<u8>().len() == 2);
+    assert!(type_name::<bool>().len() == 4);
+}
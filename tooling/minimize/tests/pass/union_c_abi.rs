@@ -0,0 +1,28 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union U {
+    a: u32,
+    b: [u8; 4],
+}
+
+extern "C" fn roundtrip(u: U) -> U {
+    u
+}
+
+extern "C" fn read_byte(u: U, i: usize) -> u8 {
+    unsafe { u.b[i] }
+}
+
+fn main() {
+    let u = U { a: u32::from_ne_bytes([1, 2, 3, 4]) };
+    let u = roundtrip(u);
+    for i in 0..4 {
+        print(read_byte(u, i));
+    }
+
+    let v = roundtrip(U { b: [5, 6, 7, 8] });
+    print(unsafe { v.a } == u32::from_ne_bytes([5, 6, 7, 8]));
+}
@@ -0,0 +1,31 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Halves {
+    lo: u16,
+    hi: u16,
+}
+
+union U {
+    word: u32,
+    halves: Halves,
+    byte: u8,
+}
+
+fn main() {
+    let mut u = U { word: 0x11223344 };
+    unsafe {
+        // Writing one field only overwrites the bytes of that field.
+        u.byte = 0xff;
+        print(u.word == 0x112233ff);
+        u.halves.hi = 0xaabb;
+        print(u.word == 0xaabb33ff);
+        print(u.halves.lo == 0x33ff);
+        // Reading a smaller field sees the overlapping bytes of the last write.
+        u.word = 0x01020304;
+        print(u.byte);
+        print(u.halves.hi);
+    }
+}
@@ -0,0 +1,8 @@
+extern crate intrinsics;
+use intrinsics::*;
+union U { s: &'static [u8], x: usize }
+fn main() {
+    let u = U { s: &[1, 2, 3] };
+    print(unsafe { u.s.len() });
+    print(unsafe { u.x } != 0);
+}
@@ -0,0 +1,17 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+fn f(x: u32) -> u32 {
+    unsafe { std::intrinsics::assume(x < 10) };
+    if x >= 10 {
+        // The `assume` above makes this unreachable.
+        unsafe { std::intrinsics::unreachable() }
+    }
+    x + 1
+}
+
+fn main() {
+    print(f(3));
+}
@@ -0,0 +1,17 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// `Vec` allocates through the global allocator shims and grows with `__rust_realloc`.
+// Translating its growth path also needs the `write_bytes` and `copy_nonoverlapping` intrinsics.
+fn main() {
+    let mut v: Vec<u32> = Vec::with_capacity(2);
+    v.push(1);
+    v.push(2);
+    // This exceeds the capacity, so the buffer is reallocated.
+    v.push(3);
+
+    print(v[0]);
+    print(v[1]);
+    print(v[2]);
+    print(v.len());
+}
@@ -0,0 +1,35 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+trait Trait {}
+impl Trait for u8 {}
+
+fn main() {
+    let arr = [1u8, 2, 3, 4];
+    let short: &[u8] = &arr[..2];
+    let long: &[u8] = &arr[..3];
+    let same: &[u8] = &arr[..2];
+
+    // Same address but different length: not equal.
+    print(std::ptr::eq(short, long));
+    // Same address and length: equal.
+    print(std::ptr::eq(short, same));
+
+    // `==` on raw wide pointers compares the metadata, too.
+    let short = short as *const [u8];
+    let long = long as *const [u8];
+    let same = same as *const [u8];
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    {
+        print(short == long);
+        print(short == same);
+        print(short != long);
+        // The address ties, so the length decides the order.
+        print(short < long);
+    }
+
+    let x = 0u8;
+    let a: &dyn Trait = &x;
+    let b: &dyn Trait = &x;
+    print(std::ptr::eq(a, b));
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    write(1, b"hel");
+    write(1, b"lo");
+    print(42);
+    let bytes = [b'a', b'b', b'\n'];
+    write(2, &bytes);
+}
@@ -0,0 +1,9 @@
+fn get(s: &[u32], i: usize) -> u32 {
+    unsafe { std::hint::assert_unchecked(i < s.len()) };
+    s[i]
+}
+
+fn main() {
+    let a = [1u32, 2, 3];
+    get(&a, 3);
+}
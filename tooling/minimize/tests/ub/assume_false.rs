@@ -0,0 +1,9 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+fn main() {
+    let a = [1u32, 2, 3];
+    let s = &a[..];
+    let i = 3;
+    unsafe { std::intrinsics::assume(i < s.len()) };
+}
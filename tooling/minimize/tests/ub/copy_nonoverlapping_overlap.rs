@@ -0,0 +1,8 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+fn main() {
+    let mut a = [1u8, 2, 3, 4];
+    let p = a.as_mut_ptr();
+    unsafe { std::intrinsics::copy_nonoverlapping(p, p.add(1), 2) };
+}
@@ -0,0 +1,10 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    print(unsafe { std::intrinsics::cttz_nonzero(black_box(0u32)) });
+}
+
+fn black_box<T>(t: T) -> T { t }
@@ -0,0 +1,10 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    print(unsafe { std::intrinsics::exact_div(i32::MIN, black_box(-1)) });
+}
+
+fn black_box<T>(t: T) -> T { t }
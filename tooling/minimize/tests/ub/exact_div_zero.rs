@@ -0,0 +1,10 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    print(unsafe { std::intrinsics::exact_div(8i32, black_box(0)) });
+}
+
+fn black_box<T>(t: T) -> T { t }
@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[allow(dead_code)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+// The match reads the discriminant directly from memory, without loading the whole value.
+fn total(c: *const Color) -> u8 {
+    unsafe {
+        match *c {
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Blue => 3,
+        }
+    }
+}
+
+fn main() {
+    let x = 7u8;
+    print(total(&x as *const u8 as *const Color));
+}
@@ -0,0 +1,6 @@
+use std::ptr::NonNull;
+
+fn main() {
+    let p: *const u8 = std::ptr::null();
+    let _nn = unsafe { *(&p as *const *const u8 as *const NonNull<u8>) };
+}
@@ -0,0 +1,6 @@
+use std::num::NonZeroU8;
+
+fn main() {
+    let x = 0u8;
+    let _nz = unsafe { *(&x as *const u8 as *const NonZeroU8) };
+}
@@ -0,0 +1,16 @@
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+// Make sure `b` is misaligned for `u32`.
+#[repr(C, align(4))]
+struct Wrapper(Packed);
+
+fn main() {
+    let w = Wrapper(Packed { a: 1, b: 42 });
+    let r = &raw const w.0.b;
+    // The pointer itself is fine, but an aligned read through it is not.
+    let _val = unsafe { *r };
+}
@@ -0,0 +1,6 @@
+use std::mem::MaybeUninit;
+
+fn main() {
+    let x = MaybeUninit::<u32>::uninit();
+    let _y = unsafe { std::ptr::read(x.as_ptr()) };
+}
@@ -0,0 +1,8 @@
+use std::slice;
+
+fn main() {
+    let a = [0u32; 2];
+    let ptr = (a.as_ptr() as *const u8).wrapping_add(1) as *const u32;
+    // The resulting reference is misaligned.
+    let _s = unsafe { slice::from_raw_parts(ptr, 1) };
+}
@@ -0,0 +1,11 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+use std::intrinsics::transmute_unchecked;
+
+fn main() {
+    let a = [1u8, 2, 3];
+    let thin: *const u8 = a.as_ptr();
+    // `mem::transmute` rejects this at compile time, but `transmute_unchecked` does not.
+    let _wide: &[u8] = unsafe { transmute_unchecked(thin) };
+}
@@ -0,0 +1,22 @@
+//@ compile-flags: --minimize-tree-borrows
+
+// Check that writing through a shared reference to a struct without `UnsafeCell`s is UB,
+// even if the struct is nested in one that has a `Cell` next to it.
+
+use std::cell::Cell;
+
+struct Inner {
+    n: u32,
+}
+
+struct Outer {
+    inner: Inner,
+    _c: Cell<u32>,
+}
+
+fn main() {
+    let o = Outer { inner: Inner { n: 1 }, _c: Cell::new(2) };
+    let r = &o.inner; // (r, Frozen): `Inner` is `Freeze`
+    let p = r as *const Inner as *mut Inner;
+    unsafe { (*p).n = 3 }; // UB! Child Write to Frozen.
+}
@@ -0,0 +1,20 @@
+//@ compile-flags: --minimize-tree-borrows
+
+// Check that `PhantomData` fields do not count as interior mutability,
+// even if their type parameter contains an `UnsafeCell`.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+struct S {
+    n: u32,
+    _p: PhantomData<*mut u8>,
+    _c: PhantomData<Cell<u32>>,
+}
+
+fn main() {
+    let s = S { n: 1, _p: PhantomData, _c: PhantomData };
+    let r = &s; // (r, Frozen): `S` is `Freeze`
+    let p = r as *const S as *mut S;
+    unsafe { (*p).n = 3 }; // UB! Child Write to Frozen.
+}
@@ -0,0 +1,34 @@
+//@ compile-flags: --minimize-tree-borrows
+
+// Check that using a reborrow obtained through `DerefMut` after a write through another
+// `DerefMut` reborrow of the same value is UB.
+
+use std::ops::{Deref, DerefMut};
+
+struct Wrapper {
+    val: u32,
+}
+
+impl Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &u32 {
+        &self.val
+    }
+}
+
+impl DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut u32 {
+        &mut self.val
+    }
+}
+
+fn main() {
+    let mut w = Wrapper { val: 1 };
+    let raw: *mut u32 = &mut *w; // (raw, Reserved)
+    let r: &mut u32 = unsafe { &mut *raw }; // (raw, Reserved) -> (r, Reserved)
+    *r = 2; // (raw, Active) -> (r, Active)
+    // The aliasing reference also comes from `DerefMut`, so it is foreign to `raw`.
+    let alias: &mut u32 = &mut *w; // (alias, Reserved)
+    *alias = 3; // (alias, Active), (raw, Disabled) -> (r, Disabled)
+    *r = 4; // UB! Child Write to Disabled.
+}
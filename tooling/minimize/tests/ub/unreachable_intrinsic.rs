@@ -0,0 +1,7 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+fn main() {
+    // rustc lowers this call to an `Unreachable` terminator.
+    unsafe { std::intrinsics::unreachable() }
+}
@@ -0,0 +1,9 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+fn main() {
+    let mut a = [0u32; 2];
+    let p = a.as_mut_ptr() as *mut u8;
+    // Alignment is required even when nothing is written.
+    unsafe { std::intrinsics::write_bytes(p.add(1) as *mut u32, 0, 0) };
+}
@@ -69,5 +69,11 @@ fn main() -> Result<()> {
         cfg("tests/pass", Mode::Pass),
         cfg("tests/ub", Mode::Panic),
         cfg("tests/panic", Mode::Panic),
+        // Programs that minimize rejects because they use unsupported features.
+        cfg("tests/unsupported", Mode::Panic),
+        // Programs that exceed a resource limit set on the command line.
+        cfg("tests/limits", Mode::Panic),
+        // Programs that leak memory, which is not UB but makes minimize fail.
+        cfg("tests/leak", Mode::Panic),
     ])
 }
@@ -0,0 +1,7 @@
+extern "C" {
+    static FOO: u8;
+}
+
+fn main() {
+    let _x = unsafe { FOO };
+}
@@ -0,0 +1,10 @@
+#![feature(naked_functions)]
+
+#[naked]
+unsafe extern "C" fn answer() -> u32 {
+    core::arch::asm!("mov eax, 42", "ret", options(noreturn));
+}
+
+fn main() {
+    let _x = unsafe { answer() };
+}
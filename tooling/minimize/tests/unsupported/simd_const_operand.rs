@@ -0,0 +1,14 @@
+#![feature(repr_simd, core_intrinsics)]
+#![allow(internal_features)]
+use std::intrinsics::simd::simd_add;
+
+#[repr(simd)]
+#[derive(Clone, Copy)]
+struct U32x4([u32; 4]);
+
+const ONES: U32x4 = U32x4([1, 1, 1, 1]);
+
+fn main() {
+    let a = U32x4([1, 2, 3, 4]);
+    let _sum: U32x4 = unsafe { simd_add(a, ONES) };
+}
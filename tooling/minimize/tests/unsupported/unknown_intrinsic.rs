@@ -0,0 +1,6 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+fn main() {
+    let _x = std::intrinsics::bitreverse(1u32);
+}
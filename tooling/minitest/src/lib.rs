@@ -1,7 +1,9 @@
 #![cfg(test)]
 
 pub use miniutil::BasicMem;
+pub use miniutil::TreeBorrowMem;
 pub use miniutil::build::*;
+pub use miniutil::equiv::*;
 pub use miniutil::fmt::*;
 pub use miniutil::run::*;
 
@@ -14,6 +16,7 @@ pub use minirust_rs::mem::*;
 pub use minirust_rs::prelude::NdResult;
 pub use minirust_rs::prelude::*;
 
+pub use std::collections::HashSet;
 pub use std::format;
 pub use std::string::String;
 
@@ -21,14 +24,28 @@ mod tests;
 
 #[track_caller]
 pub fn assert_stop<M: Memory>(prog: Program) {
-    assert_eq!(run_program::<M>(prog), TerminationInfo::MachineStop);
+    assert_eq!(run_program::<M>(prog), TerminationInfo::MachineStop(Int::ZERO));
 }
 
+/// Run the program `attempts` times and check that it always stops.
+/// Returns how many distinct outputs to stdout were observed.
 #[track_caller]
-pub fn assert_stop_always<M: Memory>(prog: Program, attempts: usize) {
+pub fn assert_stop_always<M: Memory>(prog: Program, attempts: usize) -> usize {
+    let mut outcomes = HashSet::new();
     for _ in 0..attempts {
-        assert_eq!(run_program::<M>(prog), TerminationInfo::MachineStop);
+        let (info, stdout) = run_program_with_stdout::<M>(prog);
+        assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+        outcomes.insert(stdout);
     }
+    outcomes.len()
+}
+
+/// Run the program `attempts` times and return how many distinct outcomes were observed.
+///
+/// An outcome consists of how the program terminated and what it printed to stdout.
+/// This helps to judge whether a given number of attempts covers the possible executions.
+pub fn distinct_outcomes<M: Memory>(prog: Program, attempts: usize) -> usize {
+    run_program_outcomes::<M>(prog, attempts).len()
 }
 
 #[track_caller]
@@ -45,12 +62,25 @@ pub fn assert_ub<M: Memory>(prog: Program, msg: &str) {
     );
 }
 
+/// Like `assert_ub`, but only requires the UB message to contain `substr`.
+#[track_caller]
+pub fn assert_ub_contains<M: Memory>(prog: Program, substr: &str) {
+    let TerminationInfo::Ub(msg) = run_program::<M>(prog) else {
+        panic!("program does not have UB!")
+    };
+    let msg = msg.get_internal();
+    assert!(
+        msg.contains(substr),
+        "program has UB with message {msg:?}, which does not contain {substr:?}"
+    );
+}
+
 #[track_caller]
 pub fn assert_ub_eventually<M: Memory>(prog: Program, attempts: usize, msg: &str) {
     let msg = minirust_rs::prelude::String::from_internal(msg.to_string());
     for _ in 0..attempts {
         match run_program::<M>(prog) {
-            TerminationInfo::MachineStop => continue,
+            TerminationInfo::MachineStop(_) => continue,
             TerminationInfo::Ub(res) if res == msg => {
                 // Got the expected result.
                 return;
@@ -82,10 +112,21 @@ pub fn assert_ub_expr<T: TypeConv, M: Memory>(expr: ValueExpr, msg: &str) {
 
 #[track_caller]
 pub fn assert_ill_formed<M: Memory>(prog: Program, msg: &str) {
-    let TerminationInfo::IllFormed(info) = run_program::<M>(prog) else {
+    let TerminationInfo::IllFormed(info, _) = run_program::<M>(prog) else {
+        panic!("program is not ill formed!")
+    };
+    assert_eq!(info.get_internal(), msg, "program is ill-formed with a different error message");
+}
+
+/// Like `assert_ill_formed`, but also checks where the problem is, formatted like `f0, bb2, statement 1`.
+#[track_caller]
+pub fn assert_ill_formed_at<M: Memory>(prog: Program, msg: &str, location: &str) {
+    let TerminationInfo::IllFormed(info, loc) = run_program::<M>(prog) else {
         panic!("program is not ill formed!")
     };
     assert_eq!(info.get_internal(), msg, "program is ill-formed with a different error message");
+    let loc = loc.map(fmt_ill_formed_location);
+    assert_eq!(loc.as_deref(), Some(location), "program is ill-formed at a different location");
 }
 
 #[track_caller]
@@ -102,19 +143,28 @@ pub fn assert_memory_leak<M: Memory>(prog: Program) {
 /// This automatically fails if the program does not terminate correctly if the data race did not occur.
 #[track_caller]
 pub fn has_data_race<M: Memory>(prog: Program) -> bool {
-    let data_race_string = minirust_rs::prelude::String::from_internal("Data race".to_string());
+    data_race_report::<M>(prog).is_ok()
+}
 
+/// Like `has_data_race`, but returns the two conflicting accesses of a detected data race.
+///
+/// If there was no data race, returns how many distinct outcomes were observed instead.
+#[track_caller]
+pub fn data_race_report<M: Memory>(
+    prog: Program,
+) -> std::result::Result<(RacingAccess, RacingAccess), usize> {
+    let mut outcomes = HashSet::new();
     for _ in 0..32 {
-        match run_program::<M>(prog) {
-            TerminationInfo::MachineStop => {}
-            TerminationInfo::Ub(ub) if ub == data_race_string => {
-                return true;
-            }
+        let (info, stdout) = run_program_with_stdout::<M>(prog);
+        match info {
+            TerminationInfo::MachineStop(_) => {}
+            TerminationInfo::DataRace { first, second } =>
+                return Ok((first.extract(), second.extract())),
             termination_info => {
                 panic!("unexpected outcome in `has_data_race`: {:?}", termination_info);
             }
         }
+        outcomes.insert((info, stdout));
     }
-
-    false
+    Err(outcomes.len())
 }
@@ -162,3 +162,22 @@ fn atomic_fetch_op() {
 
     assert_ill_formed::<BasicMem>(p, "IntrinsicOp::AtomicFetchAndOp: non atomic op");
 }
+
+/// The width of an atomic operation is determined by the types involved.
+#[test]
+fn atomic_fetch_add_u64() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u64>();
+    let prev = f.declare_local::<u64>();
+    f.storage_live(x);
+    f.storage_live(prev);
+    f.assign(x, const_int(u64::MAX - 1));
+    f.atomic_fetch(FetchBinOp::Add, prev, addr_of(x, raw_void_ptr_ty()), const_int(1u64));
+    f.assume(eq(load(prev), const_int(u64::MAX - 1)));
+    f.assume(eq(load(x), const_int(u64::MAX)));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
@@ -95,3 +95,49 @@ fn bool_xor_works() {
     let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
     assert_stop::<BasicMem>(prog);
 }
+
+/// Tests that transmuting 1 to `bool` gives `true`.
+#[test]
+fn transmute_one_to_bool() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let b = f.declare_local::<bool>();
+    f.storage_live(b);
+    f.assign(b, transmute(const_int(1u8), <bool>::get_type()));
+    f.assume(load(b));
+    f.exit();
+    let f = p.finish_function(f);
+    assert_stop::<BasicMem>(p.finish_program(f));
+}
+
+/// Tests that transmuting 2 to `bool` is UB.
+#[test]
+fn transmute_two_to_bool() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let b = f.declare_local::<bool>();
+    f.storage_live(b);
+    f.assign(b, transmute(const_int(2u8), <bool>::get_type()));
+    f.exit();
+    let f = p.finish_function(f);
+    assert_ub::<BasicMem>(p.finish_program(f), "transmuted value is not valid at new type");
+}
+
+/// Tests that loading a `bool` from a byte that is neither 0 nor 1 is UB.
+#[test]
+fn load_invalid_bool() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u8>();
+    let b = f.declare_local::<bool>();
+    f.storage_live(x);
+    f.storage_live(b);
+    f.assign(x, const_int(2u8));
+    f.assign(b, load(deref(addr_of(x, <*const bool>::get_type()), <bool>::get_type())));
+    f.exit();
+    let f = p.finish_function(f);
+    assert_ub::<BasicMem>(
+        p.finish_program(f),
+        "load at type Bool but the data in memory violates the language invariant",
+    );
+}
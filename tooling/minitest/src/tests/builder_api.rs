@@ -85,6 +85,32 @@ fn switch_int() {
     assert_stop::<BasicMem>(p);
 }
 
+#[test]
+fn switch_char() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let var = f.declare_local::<u32>();
+    f.storage_live(var);
+    for (c, expected) in [('a', 1u32), ('b', 2), ('z', 0)] {
+        f.switch_char(
+            const_int(u32::from(c)),
+            &[
+                ('a', &|f| f.assign(var, const_int(1u32))),
+                ('b', &|f| f.assign(var, const_int(2u32))),
+            ],
+            |f| f.assign(var, const_int(0u32)),
+        );
+        f.if_(eq(load(var), const_int(expected)), |_| {}, |f| f.unreachable());
+    }
+    f.storage_dead(var);
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
 #[test]
 fn while_() {
     let mut p = ProgramBuilder::new();
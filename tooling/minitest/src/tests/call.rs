@@ -126,3 +126,144 @@ fn ret_mismatch() {
     dump_program(p);
     assert_ub::<BasicMem>(p, "call ABI violation: return types are not compatible");
 }
+
+/// Builds a program whose main function calls a callee with calling convention `callee_conv`,
+/// using the calling convention `caller_conv` at the call site.
+fn conv_program(caller_conv: CallingConvention, callee_conv: CallingConvention) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let callee = {
+        let mut f = p.declare_function();
+        f.set_calling_convention(callee_conv);
+        let arg = f.declare_arg::<u32>();
+        let ret = f.declare_ret::<u32>();
+        f.assign(ret, add(load(arg), const_int(1u32)));
+        f.return_();
+        p.finish_function(f)
+    };
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.call_with_conv(caller_conv, x, fn_ptr(callee), &[by_value(const_int(41u32))]);
+    f.assume(eq(load(x), const_int(42u32)));
+    f.exit();
+    let f = p.finish_function(f);
+    p.finish_program(f)
+}
+
+#[test]
+fn call_rust_conv() {
+    let p = conv_program(CallingConvention::Rust, CallingConvention::Rust);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn call_conv_mismatch() {
+    let p = conv_program(CallingConvention::C, CallingConvention::Rust);
+    assert_ub::<BasicMem>(p, "call ABI violation: calling conventions are not the same");
+
+    let p = conv_program(CallingConvention::Rust, CallingConvention::C);
+    assert_ub::<BasicMem>(p, "call ABI violation: calling conventions are not the same");
+}
+
+/// A program whose `main` calls a function that recurses `depth` times before returning.
+fn recursion_program(depth: u32) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let rec = {
+        let mut f = p.declare_function();
+        let n = f.declare_arg::<u32>();
+        let name = f.name();
+        f.if_(
+            eq(load(n), const_int(0_u32)),
+            |f| f.return_(),
+            |f| {
+                f.call(unit_place(), fn_ptr(name), &[by_value(sub(load(n), const_int(1_u32)))]);
+                f.return_();
+            },
+        );
+        p.finish_function(f)
+    };
+
+    let main = {
+        let mut main = p.declare_function();
+        main.call(unit_place(), fn_ptr(rec), &[by_value(const_int(depth))]);
+        main.exit();
+        p.finish_function(main)
+    };
+
+    p.finish_program(main)
+}
+
+#[test]
+fn deep_recursion() {
+    // Frames live on the heap, so this does not exhaust the host stack.
+    let p = recursion_program(1_000);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn stack_depth_under_limit() {
+    // `main` plus 11 frames of the recursive function.
+    let p = recursion_program(10);
+    let info = run_program_with_max_stack_depth::<BasicMem>(p, 12);
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn stack_depth_exceeded() {
+    let p = recursion_program(11);
+    let info = run_program_with_max_stack_depth::<BasicMem>(p, 12);
+    assert_eq!(info, TerminationInfo::StackOverflow);
+}
+
+/// A program whose `main` calls `ping(depth)`, where `ping(n)` calls `pong(n)` and
+/// `pong(n)` calls `ping(n - 1)` until `n` reaches zero.
+fn mutual_recursion_program(depth: u32) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut ping = p.declare_function();
+    let mut pong = p.declare_function();
+    let (ping_name, pong_name) = (ping.name(), pong.name());
+
+    let n = ping.declare_arg::<u32>();
+    ping.if_(
+        eq(load(n), const_int(0_u32)),
+        |f| f.return_(),
+        |f| {
+            f.call(unit_place(), fn_ptr(pong_name), &[by_value(load(n))]);
+            f.return_();
+        },
+    );
+    p.finish_function(ping);
+
+    let n = pong.declare_arg::<u32>();
+    pong.call(unit_place(), fn_ptr(ping_name), &[by_value(sub(load(n), const_int(1_u32)))]);
+    pong.return_();
+    p.finish_function(pong);
+
+    let main = {
+        let mut main = p.declare_function();
+        main.call(unit_place(), fn_ptr(ping_name), &[by_value(const_int(depth))]);
+        main.exit();
+        p.finish_function(main)
+    };
+
+    p.finish_program(main)
+}
+
+#[test]
+fn mutual_recursion_under_limit() {
+    // `main` plus 5 frames each of `ping` and `pong`, and the final `ping`.
+    let p = mutual_recursion_program(5);
+    let info = run_program_with_max_stack_depth::<BasicMem>(p, 12);
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn mutual_recursion_overflow() {
+    let p = mutual_recursion_program(6);
+    let info = run_program_with_max_stack_depth::<BasicMem>(p, 12);
+    assert_eq!(info, TerminationInfo::StackOverflow);
+}
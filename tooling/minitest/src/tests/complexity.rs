@@ -0,0 +1,71 @@
+use crate::*;
+
+use miniutil::complexity::*;
+
+const U8_INTTYPE: IntType =
+    IntType { signed: Signedness::Unsigned, size: Size::from_bytes_const(1) };
+
+/// Builds `Option<Option<...<u8>...>>` nested `depth` times, with an explicit tag in front of the payload.
+fn nested_option_ty(depth: u32) -> Type {
+    let mut ty = <u8>::get_type();
+    for d in 1..=depth {
+        let size = size(u64::from(d) + 1);
+        let none =
+            enum_variant(tuple_ty(&[], size, align(1)), &[(offset(0), (U8_INTTYPE, 0.into()))]);
+        let some = enum_variant(tuple_ty(&[(offset(1), ty)], size, align(1)), &[(
+            offset(0),
+            (U8_INTTYPE, 1.into()),
+        )]);
+        ty = enum_ty::<u8>(
+            &[(0, none), (1, some)],
+            discriminator_branch::<u8>(offset(0), discriminator_invalid(), &[
+                ((0, 1), discriminator_known(0)),
+                ((1, 2), discriminator_known(1)),
+            ]),
+            size,
+            align(1),
+        );
+    }
+    ty
+}
+
+#[test]
+fn nested_enum_complexity_is_linear() {
+    let complexities: Vec<Complexity> =
+        (1..=8).map(|d| type_complexity(nested_option_ty(d))).collect();
+    for w in complexities.windows(2) {
+        // Each nesting level adds the enum, its two variant tuples, and nothing else.
+        assert_eq!(w[1].distinct_types - w[0].distinct_types, 3);
+    }
+}
+
+/// A program with `count` locals of the nested enum type of depth `depth`.
+fn program_with_locals(count: usize, depth: u32) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    for _ in 0..count {
+        let x = f.declare_local_with_ty(nested_option_ty(depth));
+        f.storage_live(x);
+    }
+    f.exit();
+    let f = p.finish_function(f);
+
+    p.finish_program(f)
+}
+
+/// The well-formedness check visits each occurrence of a type, so if its cost were
+/// exponential in the nesting depth, this would not terminate.
+#[test]
+fn nested_enum_wf_check_is_tractable() {
+    assert_stop::<BasicMem>(program_with_locals(1, 40));
+}
+
+#[test]
+fn program_complexity_counts_locals() {
+    let one = program_complexity(&program_with_locals(1, 4));
+    let two = program_complexity(&program_with_locals(2, 4));
+
+    // The same type again adds no distinct types.
+    assert_eq!(two, one);
+}
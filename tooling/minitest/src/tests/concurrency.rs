@@ -1,9 +1,8 @@
 use crate::*;
 
-/// This is a probabilistic test to check that the scheduler allows for multiple orderings.
-/// The probability of failure is: 2*(1/2)^20 which is about 0.0002% with a fair scheduler.
-#[test]
-fn arbitrary_order() {
+/// A program where the main thread and a second thread both write to a global,
+/// and the main thread prints the final value, which is either 1 or 2.
+fn write_1_or_2() -> Program {
     /// A function that writes 1 to the global(1).
     fn write_1() -> Function {
         let locals = [<*const ()>::get_type()];
@@ -51,7 +50,14 @@ fn arbitrary_order() {
     // global(1) is the place where both threads try to write to.
     let globals = [global_int::<u32>(), global_int::<u32>()];
 
-    let p = program_with_globals(&[f, write_1()], &globals);
+    program_with_globals(&[f, write_1()], &globals)
+}
+
+/// This is a probabilistic test to check that the scheduler allows for multiple orderings.
+/// The probability of failure is: 2*(1/2)^20 which is about 0.0002% with a fair scheduler.
+#[test]
+fn arbitrary_order() {
+    let p = write_1_or_2();
 
     // We now test, that the program can both finish with global(1) = 1 and = 2.
     let mut write_1 = false;
@@ -74,3 +80,13 @@ fn arbitrary_order() {
     assert!(write_1);
     assert!(write_2);
 }
+
+/// Checks that the randomized helpers report both possible outcomes.
+/// The probability of failure is the same as for `arbitrary_order`.
+#[test]
+fn arbitrary_order_distinct_outcomes() {
+    let p = write_1_or_2();
+
+    assert_eq!(assert_stop_always::<BasicMem>(p, 20), 2);
+    assert_eq!(distinct_outcomes::<BasicMem>(p, 20), 2);
+}
@@ -1,10 +1,5 @@
 use crate::*;
 
-enum AccessType {
-    Load,
-    Store,
-}
-
 struct AccessPattern(AccessType, Atomicity);
 
 // A block that does the access pattern on global(0): stores put the value of the "support global"
@@ -151,3 +146,39 @@ fn non_atomic_store_non_atomic_store() {
 
     assert!(has_data_race::<BasicMem>(p))
 }
+
+#[test]
+fn racy_program_outcomes() {
+    let p = racy_program(
+        AccessPattern(AccessType::Store, Atomicity::None),
+        AccessPattern(AccessType::Store, Atomicity::None),
+    );
+
+    // Depending on the scheduling, the race is detected or the program terminates normally.
+    let outcomes = run_program_outcomes::<BasicMem>(p, 64);
+    assert!(outcomes.len() >= 2, "expected several outcomes, got {outcomes:?}");
+    assert_eq!(outcomes.values().sum::<usize>(), 64);
+}
+
+#[test]
+fn data_race_reports_accesses() {
+    let p = racy_program(
+        AccessPattern(AccessType::Store, Atomicity::None),
+        AccessPattern(AccessType::Load, Atomicity::Atomic),
+    );
+
+    let (first, second) = data_race_report::<BasicMem>(p).expect("program should have a data race");
+    // Either thread may be the one to detect the race.
+    let (main, spawned) = if first.thread == Int::ZERO { (first, second) } else { (second, first) };
+
+    assert_eq!(main.thread, Int::ZERO);
+    assert_eq!(main.access.ty, AccessType::Store);
+    assert_eq!(main.access.atomicity, Atomicity::None);
+    assert_eq!(spawned.thread, Int::ONE);
+    assert_eq!(spawned.access.ty, AccessType::Load);
+    assert_eq!(spawned.access.atomicity, Atomicity::Atomic);
+    // Both accesses are to the 4 bytes of `global(0)`.
+    assert_eq!(main.access.addr, spawned.access.addr);
+    assert_eq!(main.access.len, size(4));
+    assert_eq!(spawned.access.len, size(4));
+}
@@ -352,3 +352,50 @@ fn space_optimized_enum_works() {
     let program = program(&[function(Ret::No, 0, &locals, &blocks)]);
     assert_stop::<BasicMem>(program);
 }
+
+/// An enum with tag 1 for its only variant, in a union with a `u8` so we can corrupt the tag.
+fn corruptible_enum_ty() -> Type {
+    let enum_ty = enum_ty::<u8>(
+        &[(
+            0,
+            enum_variant(tuple_ty(&[], size(1), align(1)), &[(offset(0), (U8_INTTYPE, 1.into()))]),
+        )],
+        discriminator_branch::<u8>(offset(0), discriminator_invalid(), &[(
+            (1, 2),
+            discriminator_known(0),
+        )]),
+        size(1),
+        align(1),
+    );
+    union_ty(&[(offset(0), enum_ty), (offset(0), <u8>::get_type())], size(1), align(1))
+}
+
+#[test]
+fn assert_valid_discriminant_works() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let var = f.declare_local_with_ty(corruptible_enum_ty());
+    f.storage_live(var);
+    f.set_discriminant(field(var, 0), 0);
+    f.assert_valid_discriminant(field(var, 0));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn assert_valid_discriminant_corrupted() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let var = f.declare_local_with_ty(corruptible_enum_ty());
+    f.storage_live(var);
+    f.set_discriminant(field(var, 0), 0);
+    // Overwrite the tag with a value the discriminator does not accept.
+    f.assign(field(var, 1), const_int(12u8));
+    f.assert_valid_discriminant(field(var, 0));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "ValueExpr::GetDiscriminant encountered invalid discriminant.");
+}
@@ -114,3 +114,47 @@ fn downcasts_give_different_place2() {
     let prog = small_program(locals, stmts);
     assert_stop::<BasicMem>(prog);
 }
+
+/// Works: Building a dataful variant field by field, then reading the fields back.
+/// ```rust
+/// enum E { A, B(u8, u16) }
+/// ```
+#[test]
+fn init_variant_by_fields() {
+    let b_ty = tuple_ty(
+        &[(offset(1), <u8>::get_type()), (offset(2), <u16>::get_type())],
+        size(4),
+        align(2),
+    );
+    let enum_ty = enum_ty::<u8>(
+        &[
+            (
+                0,
+                enum_variant(tuple_ty(&[], size(4), align(2)), &[(
+                    offset(0),
+                    (U8_INTTYPE, 0.into()),
+                )]),
+            ),
+            (1, enum_variant(b_ty, &[(offset(0), (U8_INTTYPE, 1.into()))])),
+        ],
+        discriminator_branch::<u8>(offset(0), discriminator_invalid(), &[
+            ((0, 1), discriminator_known(0)),
+            ((1, 2), discriminator_known(1)),
+        ]),
+        size(4),
+        align(2),
+    );
+
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let e = f.declare_local_with_ty(enum_ty);
+    f.storage_live(e);
+    f.init_variant(e, 1, &[const_int(7u8), const_int(300u16)]);
+    f.assume(eq(get_discriminant(e), const_int(1u8)));
+    f.assume(eq(load(variant_field(e, 1, 0)), const_int(7u8)));
+    f.assume(eq(load(variant_field(e, 1, 1)), const_int(300u16)));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
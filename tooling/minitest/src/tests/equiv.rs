@@ -0,0 +1,125 @@
+use crate::*;
+
+/// A program whose `main` stores a constant to a local, calls `callee` and exits.
+/// All names are given explicitly, so we can build renamed copies.
+fn sample(fns: [u32; 2], bbs: [u32; 2], locals: [u32; 2], constant: u32) -> Program {
+    let [main, callee] = fns.map(|n| FnName(Name::from_internal(n)));
+    let [bb_call, bb_exit] = bbs.map(|n| BbName(Name::from_internal(n)));
+    let [ret, x] = locals.map(|n| LocalName(Name::from_internal(n)));
+
+    let call = BasicBlock {
+        statements: list![
+            Statement::StorageLive(x),
+            assign(PlaceExpr::Local(x), const_int(constant))
+        ],
+        terminator: Terminator::Call {
+            callee: fn_ptr(callee),
+            calling_convention: CallingConvention::C,
+            arguments: list![],
+            ret: unit_place(),
+            next_block: Some(bb_exit),
+        },
+    };
+    let exit = BasicBlock { statements: list![], terminator: exit() };
+    let main_fn = Function {
+        locals: [(ret, <()>::get_type()), (x, <u32>::get_type())].into_iter().collect(),
+        args: list![],
+        ret,
+        calling_convention: CallingConvention::C,
+        blocks: [(bb_call, call), (bb_exit, exit)].into_iter().collect(),
+        start: bb_call,
+    };
+
+    let callee_fn = Function {
+        locals: [(ret, <()>::get_type())].into_iter().collect(),
+        args: list![],
+        ret,
+        calling_convention: CallingConvention::C,
+        blocks: [(bb_call, BasicBlock { statements: list![], terminator: Terminator::Return })]
+            .into_iter()
+            .collect(),
+        start: bb_call,
+    };
+
+    Program {
+        functions: [(main, main_fn), (callee, callee_fn)].into_iter().collect(),
+        start: main,
+        globals: Map::new(),
+        traits: Map::new(),
+        vtables: Map::new(),
+    }
+}
+
+#[test]
+fn renamed_program_is_equivalent() {
+    let p = sample([0, 1], [0, 1], [0, 1], 42);
+    let renamed = sample([7, 3], [5, 2], [9, 4], 42);
+    assert_stop::<BasicMem>(p);
+    assert_stop::<BasicMem>(renamed);
+    assert_eq!(programs_equivalent(&p, &renamed), Ok(()));
+}
+
+#[test]
+fn changed_program_is_not_equivalent() {
+    let p = sample([0, 1], [0, 1], [0, 1], 42);
+    let changed = sample([7, 3], [5, 2], [9, 4], 43);
+    let diff = programs_equivalent(&p, &changed).unwrap_err().to_string();
+    assert!(diff.contains("-     _1 = 42;"), "{diff}");
+    assert!(diff.contains("+     _1 = 43;"), "{diff}");
+}
+
+/// Renaming has to be consistent: two locals cannot be merged into one.
+#[test]
+fn merged_locals_are_not_equivalent() {
+    let p = sample([0, 1], [0, 1], [0, 1], 42);
+    // The return local and `x` are the same here, so the constant is stored to the return local.
+    let merged = sample([0, 1], [0, 1], [0, 0], 42);
+    assert!(programs_equivalent(&p, &merged).is_err());
+}
+
+/// Adds two unreachable functions to `p`, one returning and one with unreachable code.
+fn with_unreachable_fns(p: Program, [returns, unreachable]: [u32; 2]) -> Program {
+    let ret = LocalName(Name::from_internal(0));
+    let bb = BbName(Name::from_internal(0));
+    let function = |terminator| {
+        Function {
+            locals: [(ret, <()>::get_type())].into_iter().collect(),
+            args: list![],
+            ret,
+            calling_convention: CallingConvention::C,
+            blocks: [(bb, BasicBlock { statements: list![], terminator })].into_iter().collect(),
+            start: bb,
+        }
+    };
+    let mut functions = p.functions;
+    functions.insert(FnName(Name::from_internal(returns)), function(Terminator::Return));
+    functions.insert(FnName(Name::from_internal(unreachable)), function(Terminator::Unreachable));
+    Program { functions, ..p }
+}
+
+/// Unreachable functions are matched up in the order of their names, so swapping their order is
+/// not recognized as a renaming.
+#[test]
+fn unreachable_fns_are_matched_by_name_order() {
+    let base = sample([0, 1], [0, 1], [0, 1], 42);
+    let p = with_unreachable_fns(base, [10, 11]);
+    let same_order = with_unreachable_fns(base, [20, 21]);
+    let swapped_order = with_unreachable_fns(base, [21, 20]);
+    assert_eq!(programs_equivalent(&p, &same_order), Ok(()));
+    assert!(programs_equivalent(&p, &swapped_order).is_err());
+}
+
+/// Diffs too large for a minimal diff show the whole changed range.
+#[test]
+fn large_diff_shows_changed_range() {
+    let left: Vec<String> = (0..2000).map(|i| format!("a{i}")).collect();
+    let right: Vec<String> = (0..2000).map(|i| format!("b{i}")).collect();
+    let diff = Diff {
+        left: format!("start\n{}\nend", left.join("\n")),
+        right: format!("start\n{}\nend", right.join("\n")),
+    }
+    .to_string();
+    assert!(diff.starts_with("  start\n- a0\n"), "{diff}");
+    assert!(diff.contains("- a1999\n+ b0\n"), "{diff}");
+    assert!(diff.ends_with("+ b1999\n  end\n"), "{diff}");
+}
@@ -0,0 +1,31 @@
+use crate::*;
+
+#[test]
+fn exit_code_works() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    f.exit_code(const_int(42i32));
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_eq!(run_program::<BasicMem>(p), TerminationInfo::MachineStop(Int::from(42)));
+}
+
+#[test]
+fn exit_without_code() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_eq!(run_program::<BasicMem>(p), TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn exit_code_wrong_type() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    f.exit_code(const_int(42u32));
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "invalid argument for `Exit` intrinsic: not an `i32`");
+}
@@ -0,0 +1,204 @@
+//! Golden tests for the pretty-printer: the output of `fmt_program` for a few representative
+//! programs is compared against the files in `minitest/golden`.
+//! Run with `BLESS=1` to update the golden files after an intended change of the format.
+
+use crate::*;
+
+use std::path::PathBuf;
+
+#[track_caller]
+fn assert_golden(name: &str, prog: Program) {
+    // Make sure we only lock down the format of programs that actually run.
+    assert_stop::<BasicMem>(prog);
+
+    let actual = fmt_program(prog);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("golden").join(format!("{name}.txt"));
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}; run with `BLESS=1`", path.display()));
+    assert!(
+        actual == expected,
+        "formatted program differs from {}; run with `BLESS=1` to update it.\nactual:\n{actual}",
+        path.display()
+    );
+}
+
+#[test]
+fn golden_ints() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let y = f.declare_local::<i8>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.assign(x, add(const_int(40u32), const_int(2u32)));
+    f.assign(y, int_cast::<i8>(div(load(x), const_int(7u32))));
+    f.assume(eq(load(y), const_int(6i8)));
+    f.print(load(x));
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("ints", p.finish_program(f));
+}
+
+#[test]
+fn golden_enums() {
+    const U8_INTTYPE: IntType =
+        IntType { signed: Signedness::Unsigned, size: Size::from_bytes_const(1) };
+
+    // `enum E { A, B(u8) }` with an explicit tag in front of the data.
+    let enum_ty = enum_ty::<u8>(
+        &[
+            (
+                0,
+                enum_variant(tuple_ty(&[], size(2), align(1)), &[(
+                    offset(0),
+                    (U8_INTTYPE, 0.into()),
+                )]),
+            ),
+            (
+                1,
+                enum_variant(tuple_ty(&[(offset(1), <u8>::get_type())], size(2), align(1)), &[(
+                    offset(0),
+                    (U8_INTTYPE, 1.into()),
+                )]),
+            ),
+        ],
+        discriminator_branch::<u8>(offset(0), discriminator_invalid(), &[
+            ((0, 1), discriminator_known(0)),
+            ((1, 2), discriminator_known(1)),
+        ]),
+        size(2),
+        align(1),
+    );
+
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let e = f.declare_local_with_ty(enum_ty);
+    f.storage_live(e);
+    f.assign(field(downcast(e, 1), 0), const_int(7u8));
+    f.set_discriminant(e, 1);
+    f.assume(eq(get_discriminant(e), const_int(1u8)));
+    f.print(load(field(downcast(e, 1), 0)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("enums", p.finish_program(f));
+}
+
+#[test]
+fn golden_unions() {
+    // `union U { x: u16, y: [u8; 2] }`
+    let union_ty = union_ty(
+        &[(offset(0), <u16>::get_type()), (offset(0), <[u8; 2]>::get_type())],
+        size(2),
+        align(2),
+    );
+
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let u = f.declare_local_with_ty(union_ty);
+    f.storage_live(u);
+    f.assign(field(u, 0), const_int(0x0102u16));
+    f.assume(eq(load(index(field(u, 1), const_int(0usize))), const_int(2u8)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("unions", p.finish_program(f));
+}
+
+#[test]
+fn golden_trait_objects() {
+    let mut p = ProgramBuilder::new();
+
+    let mut trait_a = p.declare_trait();
+    let method_foo = trait_a.declare_method();
+    let trait_a = p.finish_trait(trait_a);
+    let trait_obj_ty = trait_object_ty(trait_a);
+
+    let foo_for_u32 = {
+        let mut f = p.declare_function();
+        let self_ = f.declare_arg::<&u32>();
+        let ret = f.declare_ret::<u32>();
+        f.assign(ret, load(deref(load(self_), <u32>::get_type())));
+        f.return_();
+        p.finish_function(f)
+    };
+
+    let mut vtable = p.declare_vtable_for_ty(trait_a, <u32>::get_type());
+    vtable.add_method(method_foo, foo_for_u32);
+    let vtable = p.finish_vtable(vtable);
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.assign(x, const_int(42u32));
+    let obj = f.declare_local_with_ty(ref_ty_default_markers_for(trait_obj_ty));
+    f.storage_live(obj);
+    f.assign(
+        obj,
+        construct_wide_pointer(
+            addr_of(x, <&u32>::get_type()),
+            const_vtable(vtable, trait_a),
+            ref_ty_default_markers_for(trait_obj_ty),
+        ),
+    );
+    let ret = f.declare_local::<u32>();
+    f.storage_live(ret);
+    f.call(ret, vtable_method_lookup(get_metadata(load(obj)), method_foo), &[by_value(
+        ptr_to_ptr(get_thin_pointer(load(obj)), <&u32>::get_type()),
+    )]);
+    f.assume(eq(load(ret), const_int(42u32)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("trait_objects", p.finish_program(f));
+}
+
+#[test]
+fn golden_slices() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let arr = f.declare_local::<[u16; 3]>();
+    f.storage_live(arr);
+    f.assign(index(arr, const_int(0usize)), const_int(1u16));
+    f.assign(index(arr, const_int(1usize)), const_int(2u16));
+    f.assign(index(arr, const_int(2usize)), const_int(3u16));
+    let slice = f.declare_local::<&[u16]>();
+    f.storage_live(slice);
+    f.assign(
+        slice,
+        construct_wide_pointer(
+            addr_of(arr, <*const u16>::get_type()),
+            const_int(3usize),
+            <&[u16]>::get_type(),
+        ),
+    );
+    let elem = index(deref(load(slice), <[u16]>::get_type()), const_int(2usize));
+    f.assume(eq(load(elem), const_int(3u16)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("slices", p.finish_program(f));
+}
+
+#[test]
+fn golden_boxes() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let boxed = f.box_new::<u32>(const_int(42u32));
+    f.assume(eq(load(deref(load(boxed), <u32>::get_type())), const_int(42u32)));
+    f.box_drop::<u32>(boxed);
+    f.exit();
+    let f = p.finish_function(f);
+
+    assert_golden("boxes", p.finish_program(f));
+}
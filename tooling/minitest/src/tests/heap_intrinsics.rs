@@ -465,3 +465,177 @@ fn mem_dealloc_stack() {
     let p = program(&[f]);
     assert_ub::<BasicMem>(p, "deallocating Stack memory with Heap deallocation operation");
 }
+
+#[test]
+fn box_new_and_drop() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let boxed = f.box_new::<u32>(const_int::<u32>(42));
+    f.print(load(deref(load(boxed), <u32>::get_type())));
+    f.box_drop::<u32>(boxed);
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_eq!(get_stdout::<BasicMem>(p).unwrap(), ["42"]);
+}
+
+#[test]
+fn box_new_without_drop_leaks() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    f.box_new::<u32>(const_int::<u32>(42));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_memory_leak::<BasicMem>(p);
+}
+
+/// Builds a program that allocates a `u32` on the heap (zeroed or not), reads it, and frees it.
+fn read_fresh_heap_u32(zeroed: bool) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let ptr = f.declare_local::<*mut u32>();
+    f.storage_live(ptr);
+    let (size, align) = (const_int::<usize>(4), const_int::<usize>(4));
+    if zeroed {
+        f.allocate_zeroed(size, align, ptr);
+    } else {
+        f.allocate(size, align, ptr);
+    }
+    f.print(load(deref(load(ptr), <u32>::get_type())));
+    f.deallocate(load(ptr), size, align);
+    f.exit();
+    let f = p.finish_function(f);
+
+    p.finish_program(f)
+}
+
+#[test]
+fn allocate_zeroed_reads_zero() {
+    let p = read_fresh_heap_u32(true);
+    assert_eq!(get_stdout::<BasicMem>(p).unwrap(), ["0"]);
+}
+
+#[test]
+fn allocate_reads_uninit() {
+    let p = read_fresh_heap_u32(false);
+    assert_ub::<BasicMem>(
+        p,
+        "load at type Int(IntType { signed: Unsigned, size: Size(4 bytes) }) but the data in memory violates the language invariant",
+    );
+}
+
+#[test]
+fn allocate_zeroed_argcount() {
+    let locals = [<*const i32>::get_type()];
+
+    let b0 = block!(storage_live(0), Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::AllocateZeroed,
+        arguments: list![const_int::<usize>(4)],
+        ret: local(0),
+        next_block: None,
+    },);
+
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    let p = program(&[f]);
+    assert_ub::<BasicMem>(p, "invalid number of arguments for `AllocateZeroed` intrinsic");
+}
+
+/// Builds a program that stores `[1, 2]` in a 2-byte heap allocation and reallocates it
+/// (claiming it has size `old_size` and alignment `old_align`) to `new_size` bytes,
+/// then prints the preserved bytes and frees the new allocation.
+fn realloc_program(old_size: usize, old_align: usize, new_size: usize) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let ptr = f.declare_local::<*mut [u8; 2]>();
+    let new = f.declare_local::<*mut u8>();
+    f.storage_live(ptr);
+    f.storage_live(new);
+    f.allocate(const_int::<usize>(2), const_int::<usize>(1), ptr);
+    f.assign(
+        deref(load(ptr), <[u8; 2]>::get_type()),
+        array(&[const_int(1u8), const_int(2u8)], <u8>::get_type()),
+    );
+    f.reallocate(load(ptr), const_int(old_size), const_int(old_align), const_int(new_size), new);
+    for i in 0..usize::min(2, new_size) {
+        let byte = ptr_offset(load(new), const_int(i), InBounds::Yes);
+        f.print(load(deref(byte, <u8>::get_type())));
+    }
+    f.deallocate(load(new), const_int(new_size), const_int::<usize>(1));
+    f.exit();
+    let f = p.finish_function(f);
+
+    p.finish_program(f)
+}
+
+#[test]
+fn reallocate_grow() {
+    let p = realloc_program(2, 1, 8);
+    assert_eq!(get_stdout::<BasicMem>(p).unwrap(), ["1", "2"]);
+}
+
+#[test]
+fn reallocate_shrink() {
+    let p = realloc_program(2, 1, 1);
+    assert_eq!(get_stdout::<BasicMem>(p).unwrap(), ["1"]);
+}
+
+#[test]
+fn reallocate_wrong_size() {
+    let p = realloc_program(3, 1, 1);
+    assert_ub::<BasicMem>(p, "deallocating with incorrect size information");
+}
+
+#[test]
+fn reallocate_wrong_align() {
+    let p = realloc_program(2, 2, 8);
+    assert_ub::<BasicMem>(p, "deallocating with incorrect alignment information");
+}
+
+/// Keeps two 16-byte heap allocations live at the same time.
+fn two_allocations_program() -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let a = f.declare_local::<*mut u8>();
+    let b = f.declare_local::<*mut u8>();
+    f.storage_live(a);
+    f.storage_live(b);
+    f.allocate(const_int::<usize>(16), const_int::<usize>(1), a);
+    f.allocate(const_int::<usize>(16), const_int::<usize>(1), b);
+    f.deallocate(load(a), const_int::<usize>(16), const_int::<usize>(1));
+    f.deallocate(load(b), const_int::<usize>(16), const_int::<usize>(1));
+    f.exit();
+    let f = p.finish_function(f);
+
+    p.finish_program(f)
+}
+
+#[test]
+fn max_live_bytes_under_limit() {
+    let p = two_allocations_program();
+    let info = run_program_with_max_live_bytes::<BasicMem>(p, size(32));
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn max_live_bytes_exceeded() {
+    let p = two_allocations_program();
+    let info = run_program_with_max_live_bytes::<BasicMem>(p, size(24));
+    assert_eq!(info, TerminationInfo::AllocFailure);
+}
+
+#[test]
+fn max_live_bytes_reallocate() {
+    // Growing to 8 bytes fits once the old allocation is gone, growing to 9 does not.
+    let info = run_program_with_max_live_bytes::<BasicMem>(realloc_program(2, 1, 8), size(8));
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+    let info = run_program_with_max_live_bytes::<BasicMem>(realloc_program(2, 1, 9), size(8));
+    assert_eq!(info, TerminationInfo::AllocFailure);
+}
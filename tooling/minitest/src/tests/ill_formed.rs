@@ -36,3 +36,107 @@ fn type_mismatch() {
     let p = small_program(locals, stmts);
     assert_ill_formed::<BasicMem>(p, "Statement::Assign: destination and source type differ");
 }
+
+#[test]
+fn union_chunks_miss_field_bytes() {
+    // A `u32` field, but the chunks only cover the first two bytes.
+    let ty = Type::Union {
+        fields: list![(Offset::ZERO, <u32>::get_type())],
+        size: size(4),
+        align: align(4),
+        chunks: list![(Offset::ZERO, size(2))],
+    };
+    let locals = &[ty];
+    let stmts = &[storage_live(0)];
+
+    let p = small_program(locals, stmts);
+    assert_ill_formed::<BasicMem>(p, "Type::Union: chunks do not cover all data bytes of a field");
+}
+
+#[test]
+fn union_chunks_with_padding_gap() {
+    // A `(u8, u16)` field has a padding byte at offset 1, which need not be in a chunk.
+    let ty = Type::Union {
+        fields: list![(
+            Offset::ZERO,
+            tuple_ty(
+                &[(size(0), <u8>::get_type()), (size(2), <u16>::get_type())],
+                size(4),
+                align(2)
+            )
+        )],
+        size: size(4),
+        align: align(2),
+        chunks: list![(Offset::ZERO, size(1)), (size(2), size(2))],
+    };
+    let locals = &[ty];
+    let stmts = &[storage_live(0)];
+
+    let p = small_program(locals, stmts);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn ill_formed_statement_location() {
+    let locals = &[<i32>::get_type()];
+    let stmts = &[storage_live(0), assign(local(0), const_int::<u32>(0))];
+    let p = small_program(locals, stmts);
+    assert_ill_formed_at::<BasicMem>(
+        p,
+        "Statement::Assign: destination and source type differ",
+        "f0, bb0, statement 1",
+    );
+}
+
+#[test]
+fn ill_formed_terminator_location() {
+    let b0 = block!(goto(1));
+    let b1 = block!(switch_int::<u8>(const_bool(true), &[], 0));
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program(&[f]);
+    assert_ill_formed_at::<BasicMem>(
+        p,
+        "Terminator::Switch: switch is not Int",
+        "f0, bb1, terminator",
+    );
+}
+
+/// A program with a pointer-sized global that points to `target`.
+fn program_with_relocation(target: RelocationTarget) -> Program {
+    let global = Global {
+        bytes: List::from_elem(Some(0), <*const u8>::get_size().bytes()),
+        relocations: list![(Offset::ZERO, target)],
+        align: <*const u8>::get_align(),
+    };
+    let mut p = small_program(&[], &[]);
+    p.globals.insert(GlobalName(Name::from_internal(0)), global);
+    p
+}
+
+#[test]
+fn global_points_to_unknown_function() {
+    let p = program_with_relocation(RelocationTarget::Fn(FnName(Name::from_internal(42))));
+    assert_ill_formed::<BasicMem>(p, "Program: global points to unknown function");
+}
+
+#[test]
+fn global_points_to_unknown_vtable() {
+    let p = program_with_relocation(RelocationTarget::VTable(VTableName(Name::from_internal(0))));
+    assert_ill_formed::<BasicMem>(p, "Program: global points to unknown vtable");
+}
+
+#[test]
+fn union_chunks_overlap() {
+    // The chunks cover the `u32` field, but byte 2 is in both of them.
+    let ty = Type::Union {
+        fields: list![(Offset::ZERO, <u32>::get_type())],
+        size: size(4),
+        align: align(4),
+        chunks: list![(Offset::ZERO, size(3)), (offset(2), size(2))],
+    };
+    let locals = &[ty];
+    let stmts = &[storage_live(0)];
+
+    let p = small_program(locals, stmts);
+    assert_ill_formed::<BasicMem>(p, "Type::Union: chunks overlap");
+}
@@ -46,6 +46,22 @@ fn div_zero() {
     assert_ub::<BasicMem>(p, "division by zero");
 }
 
+/// Only checks the category of the UB.
+#[test]
+fn div_zero_contains() {
+    let locals = [<u8>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), div(const_int::<u8>(7), const_int::<u8>(0))),
+        exit()
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    let p = program(&[f]);
+    assert_ub_contains::<BasicMem>(p, "by zero");
+}
+
 #[test]
 fn rem_zero() {
     let locals = [<i32>::get_type()];
@@ -126,6 +142,22 @@ fn div_exact_remainder() {
     assert_ub::<BasicMem>(p, "non-zero remainder in exact division");
 }
 
+#[test]
+fn div_exact_zero() {
+    assert_ub_expr::<i32, BasicMem>(
+        div_exact(const_int::<i32>(4), const_int::<i32>(0)),
+        "division by zero",
+    );
+}
+
+#[test]
+fn div_exact_overflow() {
+    assert_ub_expr::<i32, BasicMem>(
+        div_exact(const_int::<i32>(i32::MIN), const_int::<i32>(-1)),
+        "overflow in division",
+    );
+}
+
 /// Test that IntBinOp::BitAnd works for ints
 #[test]
 fn bit_and_int_works() {
@@ -195,6 +227,27 @@ fn bit_or_int_works() {
     assert_stop::<BasicMem>(prog);
 }
 
+/// Test that IntBinOp::Min and IntBinOp::Max work for ints
+#[test]
+fn min_max_int_works() {
+    let locals = [];
+    let unreach_block = 5;
+    let min = |x, y| int_min(const_int::<i32>(x), const_int::<i32>(y));
+    let max = |x, y| int_max(const_int::<i32>(x), const_int::<i32>(y));
+
+    let blocks = [
+        block!(if_(eq(min(42, 50), const_int::<i32>(42)), 1, unreach_block)),
+        block!(if_(eq(min(7, -42), const_int::<i32>(-42)), 2, unreach_block)),
+        block!(if_(eq(max(-42, -50), const_int::<i32>(-42)), 3, unreach_block)),
+        block!(if_(eq(max(42, 42), const_int::<i32>(42)), 4, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop::<BasicMem>(prog);
+}
+
 /// Test that IntBinOp::BitXor works for ints
 #[test]
 fn bit_xor_int_works() {
@@ -254,6 +307,27 @@ fn count_ones_works() {
     assert_stop::<BasicMem>(p);
 }
 
+#[test]
+fn trailing_zeros_works() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+
+    fn check<T: TypeConv + Into<Int>>(f: &mut FunctionBuilder, val: T, expect: u32) {
+        f.assume(eq(trailing_zeros(const_int(val)), const_int(expect)));
+    }
+
+    check(&mut f, 0_u8, 0_u8.trailing_zeros());
+    check(&mut f, 0_i64, 0_i64.trailing_zeros());
+    check(&mut f, -128_i8, (-128_i8).trailing_zeros());
+    check(&mut f, 2934824_i32, 2934824_i32.trailing_zeros());
+    check(&mut f, 98238923898080_u64, 98238923898080_u64.trailing_zeros());
+
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
 #[test]
 fn shl_works() {
     let mut p = ProgramBuilder::new();
@@ -661,3 +735,115 @@ fn overflow_ill_formed_right() {
     let p = p.finish_program(f);
     assert_ill_formed::<BasicMem>(p, "BinOp::IntWithOverflow: invalid right type");
 }
+
+#[test]
+fn disjoint_bit_or_works() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    f.assume(eq(bit_or_disjoint(const_int(0b1010u8), const_int(0b0101u8)), const_int(0b1111u8)));
+    f.assume(eq(bit_or_disjoint(const_int(i8::MIN), const_int(1i8)), const_int(i8::MIN + 1)));
+    f.assume(eq(bit_or_disjoint(const_int(0u8), const_int(0u8)), const_int(0u8)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn disjoint_bit_or_ub() {
+    assert_ub_expr::<u8, BasicMem>(
+        bit_or_disjoint(const_int(0b0110u8), const_int(0b0011u8)),
+        "overlapping bits in disjoint bitwise or",
+    );
+
+    assert_ub_expr::<i8, BasicMem>(
+        bit_or_disjoint(const_int(-1i8), const_int(i8::MIN)),
+        "overlapping bits in disjoint bitwise or",
+    );
+}
+
+#[test]
+fn carrying_mul_add_works() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let max = const_int(u64::MAX);
+    let res = carrying_mul_add::<u64, u128>(max, max, max, max);
+    let var = f.declare_local_with_ty(tuple_ty(
+        &[(size(0), <u64>::get_type()), (size(8), <u64>::get_type())],
+        size(16),
+        align(8),
+    ));
+    f.storage_live(var);
+    // `MAX * MAX + MAX + MAX` is exactly `u128::MAX`.
+    f.assign(var, res);
+    f.assume(eq(load(field(var, 0)), max));
+    f.assume(eq(load(field(var, 1)), max));
+
+    let res = carrying_mul_add::<u64, u128>(
+        const_int(1u64 << 63),
+        const_int(4u64),
+        const_int(7u64),
+        const_int(1u64),
+    );
+    f.assign(var, res);
+    f.assume(eq(load(field(var, 0)), const_int(8u64)));
+    f.assume(eq(load(field(var, 1)), const_int(2u64)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+/// Checks every binary integer operation on a grid of interesting operand values
+/// against the result computed by Rust itself.
+/// Operand pairs for which the MiniRust operation is UB are skipped.
+macro_rules! check_int_grid {
+    ($f:expr, $t:ty) => {{
+        let vals: Vec<$t> = [<$t>::MIN, <$t>::MIN + 1, 0, 1, 2, 7, <$t>::MAX - 1, <$t>::MAX]
+            .into_iter()
+            .chain((<$t>::MIN != 0).then(|| (0 as $t).wrapping_sub(1)))
+            .collect();
+        for &a in &vals {
+            for &b in &vals {
+                let (l, r) = (const_int(a), const_int(b));
+                $f.assume(eq(add(l, r), const_int(a.wrapping_add(b))));
+                $f.assume(eq(sub(l, r), const_int(a.wrapping_sub(b))));
+                $f.assume(eq(mul(l, r), const_int(a.wrapping_mul(b))));
+                if let Some(q) = a.checked_div(b) {
+                    $f.assume(eq(div(l, r), const_int(q)));
+                }
+                if let Some(m) = a.checked_rem(b) {
+                    $f.assume(eq(rem(l, r), const_int(m)));
+                }
+                // MiniRust shifts take the right operand modulo the bit width,
+                // which for negative amounts matches masking the two's complement.
+                $f.assume(eq(shl(l, r), const_int(a.wrapping_shl(b as u32))));
+                $f.assume(eq(shr(l, r), const_int(a.wrapping_shr(b as u32))));
+                $f.assume(eq(bit_and(l, r), const_int(a & b)));
+                $f.assume(eq(bit_or(l, r), const_int(a | b)));
+                $f.assume(eq(bit_xor(l, r), const_int(a ^ b)));
+            }
+        }
+    }};
+}
+
+#[test]
+fn int_ops_match_rust() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+
+    check_int_grid!(f, u8);
+    check_int_grid!(f, i8);
+    check_int_grid!(f, u32);
+    check_int_grid!(f, i32);
+
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
@@ -0,0 +1,21 @@
+use crate::*;
+
+#[test]
+fn metrics_loop_and_switch() {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), assign(local(0), const_int(0u32)), goto(1));
+    // Two cases jump to the same block, which counts as a single edge.
+    let b1 = block!(switch_int(load(local(0)), &[(3u32, 3), (4u32, 3)], 2));
+    let b2 = block!(assign(local(0), add(load(local(0)), const_int(1u32))), goto(1));
+    let b3 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[f]);
+    assert_stop::<BasicMem>(p);
+
+    let metrics = function_metrics(p);
+    assert_eq!(metrics.len(), Int::from(1));
+    let metrics = metrics.get(p.start).unwrap();
+    assert_eq!(metrics, Metrics { block_count: 4, edge_count: 4, max_fan_out: 2 });
+    assert_eq!(metrics.cyclomatic_complexity(), 2);
+}
@@ -6,6 +6,7 @@ mod bool;
 mod builder_api;
 mod call;
 mod compare_exchange;
+mod complexity;
 mod compute_align;
 mod compute_size;
 mod concurrency;
@@ -14,13 +15,17 @@ mod dereferenceable;
 mod enum_discriminant;
 mod enum_downcast;
 mod enum_representation;
+mod equiv;
+mod exit;
 mod expose;
+mod fmt_golden;
 mod heap_intrinsics;
 mod ill_formed;
 mod int;
 mod locals;
 mod locks;
 mod main;
+mod metrics;
 mod negative_index;
 mod no_preserve_padding;
 mod no_preserve_prov;
@@ -35,10 +40,13 @@ mod ptr_offset_from;
 mod raw_eq;
 mod return_;
 mod slice;
+mod snapshot;
 mod spawn_join;
+mod swap;
 mod switch;
 mod too_large_alloc;
 mod trait_object;
+mod tree_borrows;
 mod uninit_read;
 mod unreachable;
 mod unsized_struct;
@@ -11,3 +11,42 @@ fn panic() {
     let prog = prog.finish_program(start);
     assert_abort::<BasicMem>(prog, "we panicked");
 }
+
+#[test]
+fn panic_with_message() {
+    let mut prog = ProgramBuilder::new();
+
+    let mut start = prog.declare_function();
+    let msg = start.declare_local_with_ty(array_ty(<u8>::get_type(), 4));
+    start.storage_live(msg);
+    let elems: Vec<_> = b"oops".iter().map(|b| const_int(*b)).collect();
+    start.assign(msg, array(&elems, <u8>::get_type()));
+    start.panic_msg(construct_wide_pointer(
+        addr_of(msg, <*const u8>::get_type()),
+        const_int(4usize),
+        <&[u8]>::get_type(),
+    ));
+    let start = prog.finish_function(start);
+
+    let prog = prog.finish_program(start);
+    assert_abort::<BasicMem>(prog, "oops");
+}
+
+#[test]
+fn panic_with_invalid_utf8_message() {
+    let mut prog = ProgramBuilder::new();
+
+    let mut start = prog.declare_function();
+    let msg = start.declare_local_with_ty(array_ty(<u8>::get_type(), 1));
+    start.storage_live(msg);
+    start.assign(msg, array(&[const_int(0xffu8)], <u8>::get_type()));
+    start.panic_msg(construct_wide_pointer(
+        addr_of(msg, <*const u8>::get_type()),
+        const_int(1usize),
+        <&[u8]>::get_type(),
+    ));
+    let start = prog.finish_function(start);
+
+    let prog = prog.finish_program(start);
+    assert_ub::<BasicMem>(prog, "invalid argument to `Panic` intrinsic: not valid UTF-8");
+}
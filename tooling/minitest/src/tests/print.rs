@@ -17,10 +17,11 @@ fn print_success() {
 
 #[test]
 fn print_fail() {
-    let locals = [];
+    let locals = [union_ty(&[(size(0), <u32>::get_type())], size(4), align(4))];
 
     let b0 = block!(
-        print(unit(), 1), // tuples cannot be printed
+        storage_live(0),
+        print(load(local(0)), 1), // unions cannot be printed
     );
     let b1 = block!(exit());
 
@@ -47,3 +48,203 @@ fn print_wrongreturn() {
     dump_program(p);
     assert_ub::<BasicMem>(p, "invalid return type for `PrintStdout` intrinsic");
 }
+
+#[test]
+fn print_typed_values() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    f.print(const_int(42u32));
+    f.print(const_int(-7i8));
+    f.print(const_int(u128::MAX));
+    f.print(const_int(i128::MIN));
+    f.print(const_bool(true));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let values = get_printed_values::<BasicMem>(p).unwrap();
+    assert_eq!(values, [
+        vec![Value::Int(Int::from(42))],
+        vec![Value::Int(Int::from(-7))],
+        vec![Value::Int(Int::from(u128::MAX))],
+        vec![Value::Int(Int::from(i128::MIN))],
+        vec![Value::Bool(true)],
+    ]);
+}
+
+#[test]
+fn print_typed_aggregates() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    let pair_ty =
+        tuple_ty(&[(offset(0), <u8>::get_type()), (offset(1), Type::Bool)], size(2), align(1));
+    f.print(tuple(&[const_int(1u8), const_bool(false)], pair_ty));
+    f.print(addr_of(x, <*const u32>::get_type()));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let values = get_printed_values::<BasicMem>(p).unwrap();
+    assert_eq!(values[0], [Value::Tuple(list![Value::Int(Int::from(1)), Value::Bool(false)])]);
+    assert!(matches!(values[1][..], [Value::Ptr(_)]));
+}
+
+#[test]
+fn print_multiple_values() {
+    let mut p = ProgramBuilder::new();
+    let pair_ty =
+        tuple_ty(&[(size(0), <u8>::get_type()), (size(1), <u8>::get_type())], size(2), align(1));
+
+    let mut f = p.declare_function();
+    f.print_all(&[
+        const_int(1u32),
+        tuple(&[const_int(2u8), const_int(3u8)], pair_ty),
+        const_int(-3i8),
+    ]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    // Each value is on its own line, even if its rendering contains spaces.
+    assert_eq!(get_stdout::<BasicMem>(p).unwrap(), &["1", "(2, 3)", "-3"]);
+}
+
+#[test]
+fn print_no_values() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    f.print_all(&[]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    assert_eq!(get_stdout_raw::<BasicMem>(p).unwrap(), "");
+}
+
+#[test]
+fn eprint_multiple_values() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    f.eprint_all(&[const_int(1u32), const_bool(false)]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    assert_stop::<BasicMem>(p);
+}
+
+/// Declares a local holding `bytes` and returns a `&[u8]` pointing to it.
+fn byte_slice(f: &mut FunctionBuilder, bytes: &[u8]) -> ValueExpr {
+    let arr = f.declare_local_with_ty(array_ty(<u8>::get_type(), bytes.len()));
+    f.storage_live(arr);
+    let elems: Vec<_> = bytes.iter().map(|b| const_int(*b)).collect();
+    f.assign(arr, array(&elems, <u8>::get_type()));
+    construct_wide_pointer(
+        addr_of(arr, <*const u8>::get_type()),
+        const_int(bytes.len()),
+        <&[u8]>::get_type(),
+    )
+}
+
+#[test]
+fn write_without_newline() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let hel = byte_slice(&mut f, b"hel");
+    let lo = byte_slice(&mut f, b"lo");
+    f.write(const_int(1i32), hel);
+    f.write(const_int(1i32), lo);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    assert_eq!(get_stdout_raw::<BasicMem>(p).unwrap(), "hello");
+}
+
+#[test]
+fn write_invalid_fd() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let hi = byte_slice(&mut f, b"hi");
+    f.write(const_int(3i32), hi);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    assert_ub::<BasicMem>(
+        p,
+        "invalid first argument to `Write` intrinsic: unknown file descriptor",
+    );
+}
+
+/// Tests the rendering of pointers and aggregates.
+#[test]
+fn print_ptr_and_tuple() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<[u32; 2]>();
+    f.storage_live(x);
+    f.assign(index(x, const_int(0)), const_int(1u32));
+    f.assign(index(x, const_int(1)), const_int(2u32));
+    f.print(addr_of(x, <&[u32; 2]>::get_type()));
+    f.print(addr_of(index(x, const_int(1)), <&u32>::get_type()));
+    f.print(load(x));
+    f.print(tuple(
+        &[const_bool(false), unit(), const_int(-3i8)],
+        tuple_ty(
+            &[
+                (size(0), <bool>::get_type()),
+                (size(1), <()>::get_type()),
+                (size(1), <i8>::get_type()),
+            ],
+            size(2),
+            align(1),
+        ),
+    ));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let out = get_stdout::<BasicMem>(p).unwrap();
+    // Both pointers point into the same allocation, at offsets 0 and 4.
+    let alloc = out[0].strip_suffix("+0)").unwrap();
+    assert!(alloc.starts_with("ptr(alloc"));
+    assert_eq!(out[1], format!("{alloc}+4)"));
+    assert_eq!(&out[2..], &["(1, 2)", "(false, (), -3)"]);
+}
+
+/// Pointers are printed with an allocation id that does not change when other allocations are
+/// freed. Pointers one past the end of an allocation are printed relative to that allocation.
+#[test]
+fn print_ptr_stable_alloc_id() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let y = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.storage_live(y);
+    let y_ptr = addr_of(y, <*const u32>::get_type());
+    f.print(y_ptr);
+    f.storage_dead(x);
+    f.print(y_ptr);
+    f.print(ptr_offset(y_ptr, const_int(4usize), InBounds::Yes));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let out = get_stdout::<BasicMem>(p).unwrap();
+    let alloc = out[0].strip_suffix("+0)").unwrap();
+    assert!(alloc.starts_with("ptr(alloc"));
+    assert_eq!(out[1], out[0]);
+    assert_eq!(out[2], format!("{alloc}+4)"));
+}
@@ -179,3 +179,61 @@ fn invalid_arg_ty_raw_eq() {
     let p = p.finish_program(f);
     assert_ub::<BasicMem>(p, "invalid first argument to `RawEq` intrinsic: not a pointer");
 }
+
+/// A `(u8, u16)` tuple: byte 1 is padding.
+fn padded_ty() -> Type {
+    tuple_ty(&[(size(0), <u8>::get_type()), (size(2), <u16>::get_type())], size(4), align(2))
+}
+
+/// Compares two `(u8, u16)` locals with `raw_eq` and assumes the result is `expected`.
+/// `init` is called on each local, with padding byte value 0 for the left and 1 for the right one.
+fn padded_raw_eq(init: impl Fn(&mut FunctionBuilder, PlaceExpr, u8), expected: bool) -> Program {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let dest = f.declare_local::<bool>();
+    let left = f.declare_local_with_ty(padded_ty());
+    let right = f.declare_local_with_ty(padded_ty());
+
+    f.storage_live(dest);
+    f.storage_live(left);
+    f.storage_live(right);
+
+    init(&mut f, left, 0);
+    init(&mut f, right, 1);
+
+    let ptr_ty = ref_ty_default_markers_for(padded_ty());
+    f.raw_eq(dest, addr_of(left, ptr_ty), addr_of(right, ptr_ty));
+
+    f.assume(eq(bool_to_int::<u8>(load(dest)), bool_to_int::<u8>(const_bool(expected))));
+    f.exit();
+
+    let f = p.finish_function(f);
+    p.finish_program(f)
+}
+
+/// Writes all 4 bytes of `place`, with `pad` in the padding byte.
+fn init_bytes(f: &mut FunctionBuilder, place: PlaceExpr, pad: u8) {
+    let bytes = [const_int(42u8), const_int(pad), const_int(7u8), const_int(0u8)];
+    let bytes_place = deref(addr_of(place, <*mut [u8; 4]>::get_type()), <[u8; 4]>::get_type());
+    f.assign(bytes_place, array(&bytes, <u8>::get_type()));
+}
+
+#[test]
+fn padding_uninit_raw_eq() {
+    // A typed assignment of the tuple leaves the padding byte uninitialized.
+    let init = |f: &mut FunctionBuilder, place, _pad| {
+        f.assign(place, tuple(&[const_int(42u8), const_int(7u16)], padded_ty()));
+    };
+    let p = padded_raw_eq(init, true);
+    assert_ub::<BasicMem>(p, "invalid argument to `RawEq` intrinsic: byte is uninitialized");
+}
+
+#[test]
+fn padding_init_raw_eq() {
+    // With all bytes written, the padding byte is compared like any other.
+    let same_padding = |f: &mut FunctionBuilder, place, _pad| init_bytes(f, place, 0);
+    assert_stop::<BasicMem>(padded_raw_eq(same_padding, true));
+
+    // The fields are equal, but the padding differs.
+    assert_stop::<BasicMem>(padded_raw_eq(init_bytes, false));
+}
@@ -177,6 +177,35 @@ fn index_with_transmuted() {
     assert_stop::<BasicMem>(p);
 }
 
+/// Tests indexing a slice created from an array with `array_to_slice`.
+#[test]
+fn index_array_to_slice() {
+    let mut p = ProgramBuilder::new();
+
+    let f = {
+        let mut f = p.declare_function();
+        let arr = f.declare_local::<[u32; 3]>();
+        f.storage_live(arr);
+        f.assign(index(arr, const_int(0)), const_int(42_u32));
+        f.assign(index(arr, const_int(1)), const_int(43_u32));
+        f.assign(index(arr, const_int(2)), const_int(44_u32));
+        let slice = f.declare_local::<&[u32]>();
+        f.storage_live(slice);
+        f.assign(slice, array_to_slice::<u32>(arr, 2));
+        f.validate(slice, false);
+        let slice_place = deref(load(slice), <[u32]>::get_type());
+        f.assume(eq(load(index(slice_place, const_int(1))), const_int(43_u32)));
+        f.assume(eq(get_metadata(load(slice)), const_int(2_usize)));
+        // Index 2 is in bounds of the array, but not of the slice.
+        f.assign(index(slice_place, const_int(2)), const_int(0_u32));
+        f.exit();
+        p.finish_function(f)
+    };
+
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "access to out-of-bounds index");
+}
+
 #[test]
 fn index_with_constructed() {
     let mut p = ProgramBuilder::new();
@@ -0,0 +1,210 @@
+use crate::*;
+
+#[test]
+fn snapshot_mid_execution() {
+    let mut p = ProgramBuilder::new();
+
+    let callee = {
+        let mut f = p.declare_function();
+        let arg = f.declare_arg::<u32>();
+        let y = f.declare_local::<u32>();
+        f.storage_live(y);
+        f.assign(y, add(load(arg), const_int(1u32)));
+        f.return_();
+        p.finish_function(f)
+    };
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let ptr = f.declare_local::<&u32>();
+    let uninit = f.declare_local::<u8>();
+    f.storage_live(x);
+    f.storage_live(ptr);
+    f.storage_live(uninit);
+    f.assign(x, const_int(42u32));
+    f.assign(ptr, addr_of(x, <&u32>::get_type()));
+    f.call(unit_place(), fn_ptr(callee), &[by_value(load(x))]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    // Stop inside the callee, after `y` became live but before it is assigned.
+    let snapshot = debug_snapshot::<BasicMem>(p, 7);
+    let expected = "\
+after 7 steps
+thread 0 (Enabled):
+  f1, bb0, statement 5
+    _0 = 42
+    _1 = ptr(alloc3+0)
+    _2 = <invalid>
+    _3 = ()
+  f0, bb0, statement 1
+    _0 = 42
+    _1 = <invalid>
+    _2 = ()
+allocations:
+  alloc0: Function, 0 bytes
+  alloc1: Function, 0 bytes
+  alloc2: Stack, 0 bytes
+  alloc3: Stack, 4 bytes
+  alloc4: Stack, 8 bytes
+  alloc5: Stack, 1 bytes
+  alloc6: Stack, 0 bytes
+  alloc7: Stack, 4 bytes
+  alloc8: Stack, 4 bytes
+";
+    assert_eq!(snapshot, expected);
+}
+
+#[test]
+fn run_until_callee_entry() {
+    let mut p = ProgramBuilder::new();
+
+    let callee = {
+        let mut f = p.declare_function();
+        let arg = f.declare_arg::<u32>();
+        f.print(load(arg));
+        f.return_();
+        p.finish_function(f)
+    };
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.assign(x, const_int(42u32));
+    f.call(unit_place(), fn_ptr(callee), &[by_value(load(x))]);
+    f.call(unit_place(), fn_ptr(callee), &[by_value(const_int(7u32))]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    // We stop at the first call, with the argument already in place.
+    let snapshot = run_until_fn::<BasicMem>(p, callee).unwrap();
+    let expected = "\
+entered f0 after 3 steps
+thread 0 (Enabled):
+  f1, bb0, statement 2
+    _0 = 42
+    _1 = ()
+  f0, bb0, statement 0
+    _0 = 42
+    _1 = ()
+allocations:
+  alloc0: Function, 0 bytes
+  alloc1: Function, 0 bytes
+  alloc2: Stack, 0 bytes
+  alloc3: Stack, 4 bytes
+  alloc4: Stack, 0 bytes
+  alloc5: Stack, 4 bytes
+";
+    assert_eq!(snapshot, expected);
+
+    // The start function is entered before the first step.
+    let snapshot = run_until_fn::<BasicMem>(p, f).unwrap();
+    assert!(snapshot.starts_with("entered f1 after 0 steps\n"));
+}
+
+/// Functions with the same body are still told apart by name.
+#[test]
+fn run_until_fn_identical_bodies() {
+    let mut p = ProgramBuilder::new();
+
+    let mut empty_fn = || {
+        let mut f = p.declare_function();
+        f.return_();
+        p.finish_function(f)
+    };
+    let first = empty_fn();
+    let second = empty_fn();
+    let unused = empty_fn();
+
+    let mut f = p.declare_function();
+    f.call(unit_place(), fn_ptr(first), &[]);
+    f.call(unit_place(), fn_ptr(second), &[]);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let snapshot = run_until_fn::<BasicMem>(p, first).unwrap();
+    assert!(snapshot.starts_with("entered f0 after 1 steps\n"));
+    let snapshot = run_until_fn::<BasicMem>(p, second).unwrap();
+    let expected = "\
+entered f1 after 3 steps
+thread 0 (Enabled):
+  f3, bb1, statement 0
+    _0 = ()
+  f1, bb0, statement 0
+    _0 = ()
+allocations:
+  alloc0: Function, 0 bytes
+  alloc1: Function, 0 bytes
+  alloc2: Function, 0 bytes
+  alloc3: Function, 0 bytes
+  alloc4: Stack, 0 bytes
+  alloc5: Stack, 0 bytes
+";
+    assert_eq!(snapshot, expected);
+
+    // A function that is never called makes the program run to completion.
+    assert_eq!(run_until_fn::<BasicMem>(p, unused), Err(TerminationInfo::MachineStop(Int::ZERO)));
+}
+
+#[test]
+fn allocations_mid_execution() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u16>();
+    let heap = f.declare_local::<*mut u8>();
+    f.storage_live(x);
+    f.storage_live(heap);
+    f.allocate(const_int(12usize), const_int(8usize), heap);
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    // Stop right after the heap allocation was made.
+    let (info, allocs) = run_program_with_memory::<BasicMem>(p, 3);
+    assert_eq!(info, None);
+    let summary: Vec<_> = allocs
+        .iter()
+        .map(|a| (a.kind, a.size.bytes().try_to_usize().unwrap(), a.align.bytes()))
+        .collect();
+    assert_eq!(summary, [
+        (AllocationKind::Function, 0, Int::from(1)),
+        (AllocationKind::Stack, 0, Int::from(1)),
+        (AllocationKind::Stack, 2, Int::from(2)),
+        (AllocationKind::Stack, 8, Int::from(8)),
+        (AllocationKind::Heap, 12, Int::from(8)),
+    ]);
+    for a in &allocs {
+        assert_eq!(a.addr % a.align.bytes(), Int::ZERO);
+    }
+
+    // When the program terminates, we see the allocations at that point: the heap allocation leaked.
+    let (info, allocs) = run_program_with_memory::<BasicMem>(p, 100);
+    assert_eq!(info, Some(TerminationInfo::MemoryLeak));
+    assert!(allocs.iter().any(|a| a.kind == AllocationKind::Heap));
+}
+
+/// Unions cannot be printed, but snapshots still render them.
+#[test]
+fn snapshot_union() {
+    let mut p = ProgramBuilder::new();
+
+    let union_ty = union_ty(&[(offset(0), <u32>::get_type())], size(4), align(4));
+    let pair_ty =
+        tuple_ty(&[(offset(0), union_ty), (offset(4), <u32>::get_type())], size(8), align(4));
+
+    let mut f = p.declare_function();
+    let x = f.declare_local_with_ty(pair_ty);
+    f.storage_live(x);
+    f.assign(field(field(x, 0), 0), const_int(1u32));
+    f.assign(field(x, 1), const_int(2u32));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+
+    let snapshot = debug_snapshot::<BasicMem>(p, 3);
+    assert!(snapshot.contains("    _0 = (union (1 chunks), 2)\n"), "{snapshot}");
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+#[test]
+fn swap_works() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let left = f.declare_local::<[u8; 16]>();
+    let right = f.declare_local::<[u8; 16]>();
+
+    let ptr_ty = <&mut [u8; 16]>::get_type();
+
+    f.storage_live(left);
+    f.storage_live(right);
+    f.assign(left, array(&[const_int(1u8); 16], <u8>::get_type()));
+    f.assign(right, array(&[const_int(2u8); 16], <u8>::get_type()));
+
+    f.swap(addr_of(left, ptr_ty), addr_of(right, ptr_ty));
+
+    f.assume(eq(load(index(left, const_int(0usize))), const_int(2u8)));
+    f.assume(eq(load(index(left, const_int(15usize))), const_int(2u8)));
+    f.assume(eq(load(index(right, const_int(0usize))), const_int(1u8)));
+    f.assume(eq(load(index(right, const_int(15usize))), const_int(1u8)));
+    f.exit();
+
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn swap_overlapping() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let arr = f.declare_local::<[u8; 3]>();
+
+    let ptr_ty = <&mut [u8; 2]>::get_type();
+
+    f.storage_live(arr);
+    f.assign(arr, array(&[const_int(0u8); 3], <u8>::get_type()));
+
+    f.swap(
+        addr_of(index(arr, const_int(0usize)), ptr_ty),
+        addr_of(index(arr, const_int(1usize)), ptr_ty),
+    );
+    f.exit();
+
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "invalid arguments to `Swap` intrinsic: places overlap");
+}
+
+#[test]
+fn swap_raw_ptr() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let left = f.declare_local::<u32>();
+    let right = f.declare_local::<u32>();
+
+    let ptr_ty = <*mut u32>::get_type();
+
+    f.storage_live(left);
+    f.storage_live(right);
+    f.swap(addr_of(left, ptr_ty), addr_of(right, ptr_ty));
+    f.exit();
+
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "invalid argument type to `Swap` intrinsic: not a reference");
+}
+
+#[test]
+fn swap_unequal_arg_tys() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let left = f.declare_local::<u32>();
+    let right = f.declare_local::<u16>();
+
+    f.storage_live(left);
+    f.storage_live(right);
+    f.swap(addr_of(left, <&mut u32>::get_type()), addr_of(right, <&mut u16>::get_type()));
+    f.exit();
+
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(
+        p,
+        "invalid arguments to `Swap` intrinsic: types of arguments are not identical",
+    );
+}
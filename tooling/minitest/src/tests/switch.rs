@@ -50,6 +50,21 @@ fn switch_int_works() {
     assert_stop::<BasicMem>(program);
 }
 
+/// tests that negative cases match negative values of a signed integer.
+#[test]
+fn switch_int_negative() {
+    let locals = [];
+    let blocks = [
+        block!(switch_int(const_int(-1i32), &[(1i32, 3), (-1i32, 1)], 3)),
+        block!(switch_int(const_int(i32::MIN), &[(-1i32, 3), (i32::MIN, 2)], 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let program = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop::<BasicMem>(program);
+}
+
 const U8_INTTYPE: IntType =
     IntType { signed: Signedness::Unsigned, size: Size::from_bytes_const(1) };
 
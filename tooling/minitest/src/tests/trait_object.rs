@@ -72,6 +72,135 @@ fn dynamic_dispatch() {
     assert_stop::<BasicMem>(p);
 }
 
+/// Like `dynamic_dispatch`, but builds the trait object with `make_dyn`.
+#[test]
+fn dynamic_dispatch_make_dyn() {
+    let mut p = ProgramBuilder::new();
+
+    let mut trait_a = p.declare_trait();
+    let method_a_foo = trait_a.declare_method();
+    let trait_a = p.finish_trait(trait_a);
+
+    let impl_a_foo_for_usize = {
+        let mut f = p.declare_function();
+
+        let self_ = f.declare_arg::<&usize>();
+        let ret = f.declare_ret::<usize>();
+        f.assign(ret, load(deref(load(self_), <usize>::get_type())));
+        f.return_();
+
+        p.finish_function(f)
+    };
+
+    let mut usize_a_vtable = p.declare_vtable_for_ty(trait_a, <usize>::get_type());
+    usize_a_vtable.add_method(method_a_foo, impl_a_foo_for_usize);
+    let usize_a_vtable = p.finish_vtable(usize_a_vtable);
+
+    let main = {
+        let mut main = p.declare_function();
+
+        let x = main.declare_local::<usize>();
+        main.storage_live(x);
+        main.assign(x, const_int(42_usize));
+        let y = main.make_dyn(x, usize_a_vtable, trait_a, <usize>::FREEZE);
+
+        let foo_ret = main.declare_local::<usize>();
+        main.storage_live(foo_ret);
+        main.call(foo_ret, vtable_method_lookup(get_metadata(load(y)), method_a_foo), &[by_value(
+            ptr_to_ptr(get_thin_pointer(load(y)), <&usize>::get_type()),
+        )]);
+        main.assume(eq(load(x), load(foo_ret)));
+
+        main.exit();
+        p.finish_function(main)
+    };
+
+    let p = p.finish_program(main);
+    assert_stop::<BasicMem>(p);
+}
+
+/// Tests dispatching each method of a trait with several methods.
+/// ```rust
+/// trait A {
+///     fn m0(&self) -> usize;
+///     fn m1(&self) -> usize;
+///     fn m2(&self) -> usize;
+/// }
+///
+/// impl A for usize {
+///     fn m0(&self) -> usize { *self + 0 }
+///     fn m1(&self) -> usize { *self + 1 }
+///     fn m2(&self) -> usize { *self + 2 }
+/// }
+///
+/// let x: usize = 40;
+/// let y: &dyn A = &x;
+/// assert!(y.m0() == 40 && y.m1() == 41 && y.m2() == 42);
+/// ```
+#[test]
+fn dynamic_dispatch_multiple_methods() {
+    let mut p = ProgramBuilder::new();
+
+    let mut trait_a = p.declare_trait();
+    let methods: [TraitMethodName; 3] = trait_a.declare_methods();
+    let trait_a = p.finish_trait(trait_a);
+    let trait_obj_a_ty = trait_object_ty(trait_a);
+
+    let impls = [0, 1, 2].map(|offset| {
+        let mut f = p.declare_function();
+        let self_ = f.declare_arg::<&usize>();
+        let ret = f.declare_ret::<usize>();
+        f.assign(
+            ret,
+            add(load(deref(load(self_), <usize>::get_type())), const_int(offset as usize)),
+        );
+        f.return_();
+        p.finish_function(f)
+    });
+
+    let mut usize_a_vtable = p.declare_vtable_for_ty(trait_a, <usize>::get_type());
+    usize_a_vtable.add_methods(&[
+        (methods[0], impls[0]),
+        (methods[1], impls[1]),
+        (methods[2], impls[2]),
+    ]);
+    let usize_a_vtable = p.finish_vtable(usize_a_vtable);
+
+    let main = {
+        let mut main = p.declare_function();
+
+        let x = main.declare_local::<usize>();
+        main.storage_live(x);
+        main.assign(x, const_int(40_usize));
+
+        let y = main.declare_local_with_ty(ref_ty_default_markers_for(trait_obj_a_ty));
+        main.storage_live(y);
+        main.assign(
+            y,
+            construct_wide_pointer(
+                addr_of(x, <&usize>::get_type()),
+                const_vtable(usize_a_vtable, trait_a),
+                ref_ty_default_markers_for(trait_obj_a_ty),
+            ),
+        );
+
+        let ret = main.declare_local::<usize>();
+        main.storage_live(ret);
+        for (offset, method) in methods.into_iter().enumerate() {
+            main.call(ret, vtable_method_lookup(get_metadata(load(y)), method), &[by_value(
+                ptr_to_ptr(get_thin_pointer(load(y)), <&usize>::get_type()),
+            )]);
+            main.assume(eq(load(ret), const_int(40 + offset)));
+        }
+
+        main.exit();
+        p.finish_function(main)
+    };
+
+    let p = p.finish_program(main);
+    assert_stop::<BasicMem>(p);
+}
+
 /// Tests that assigning a vtable defined for a different type, but same trait is fine.
 ///
 /// This is not good code, but not defined as UB, not even in Miri.
@@ -490,3 +619,142 @@ fn ill_vtables_unaligned_size() {
 
     assert_ill_formed::<BasicMem>(p, "Program: size stored in vtable not a multiple of alignment");
 }
+
+/// Builds a program calling a `&self` method that writes through its receiver,
+/// using a `&dyn A` over an `UnsafeCell<u32>` with the given freeze-ness.
+/// ```rust
+/// trait A {
+///     fn set(&self);
+/// }
+///
+/// impl A for UnsafeCell<u32> {
+///     fn set(&self) {
+///         unsafe { *self.get() = 1 };
+///     }
+/// }
+///
+/// let x = UnsafeCell::new(0u32);
+/// let y: &dyn A = &x;
+/// y.set();
+/// assert!(unsafe { *x.get() } == 1);
+/// ```
+fn interior_mutable_trait_object(freeze: bool) -> Program {
+    type Cell = std::cell::UnsafeCell<u32>;
+
+    let mut p = ProgramBuilder::new();
+
+    let mut trait_a = p.declare_trait();
+    let method_a_set = trait_a.declare_method();
+    let trait_a = p.finish_trait(trait_a);
+
+    let impl_a_set_for_cell = {
+        let mut f = p.declare_function();
+
+        let self_ = f.declare_arg::<&Cell>();
+        f.assign(deref(load(self_), <u32>::get_type()), const_int(1_u32));
+        f.return_();
+
+        p.finish_function(f)
+    };
+
+    let mut cell_a_vtable = p.declare_vtable_for_ty(trait_a, <Cell>::get_type());
+    cell_a_vtable.add_method(method_a_set, impl_a_set_for_cell);
+    let cell_a_vtable = p.finish_vtable(cell_a_vtable);
+
+    let main = {
+        let mut main = p.declare_function();
+
+        let x = main.declare_local::<Cell>();
+        main.storage_live(x);
+        main.assign(x, const_int(0_u32));
+
+        let trait_obj_ref_ty = ref_ty_for_trait_object(trait_a, freeze);
+        let y = main.declare_local_with_ty(trait_obj_ref_ty);
+        let y_val = construct_wide_pointer(
+            addr_of(x, <&Cell>::get_type()),
+            const_vtable(cell_a_vtable, trait_a),
+            trait_obj_ref_ty,
+        );
+        main.storage_live(y);
+        main.assign(y, y_val);
+        main.validate(y, false);
+
+        let unit = main.declare_local::<()>();
+        main.storage_live(unit);
+        main.call(unit, vtable_method_lookup(get_metadata(load(y)), method_a_set), &[by_value(
+            ptr_to_ptr(get_thin_pointer(load(y)), <&Cell>::get_type()),
+        )]);
+        main.assume(eq(load(x), const_int(1_u32)));
+
+        main.exit();
+        p.finish_function(main)
+    };
+
+    p.finish_program(main)
+}
+
+/// A `dyn Trait` over an `UnsafeCell` is not `Freeze`, so writing through it is fine.
+#[test]
+fn trait_object_non_frozen() {
+    let p = interior_mutable_trait_object(<std::cell::UnsafeCell<u32>>::FREEZE);
+    assert_stop::<TreeBorrowMem>(p);
+}
+
+/// Wrongly treating the same trait object as `Freeze` makes the write UB.
+#[test]
+fn trait_object_frozen_write_ub() {
+    let p = interior_mutable_trait_object(true);
+    assert_ub::<TreeBorrowMem>(
+        p,
+        "Tree Borrows: writing to the child of a pointer with Frozen permission",
+    );
+}
+
+/// Like `trait_object_non_frozen`, but builds the trait object with `make_dyn`.
+#[test]
+fn make_dyn_non_frozen() {
+    type Cell = std::cell::UnsafeCell<u32>;
+
+    let mut p = ProgramBuilder::new();
+
+    let mut trait_a = p.declare_trait();
+    let method_a_set = trait_a.declare_method();
+    let trait_a = p.finish_trait(trait_a);
+
+    let impl_a_set_for_cell = {
+        let mut f = p.declare_function();
+
+        let self_ = f.declare_arg::<&Cell>();
+        f.assign(deref(load(self_), <u32>::get_type()), const_int(1_u32));
+        f.return_();
+
+        p.finish_function(f)
+    };
+
+    let mut cell_a_vtable = p.declare_vtable_for_ty(trait_a, <Cell>::get_type());
+    cell_a_vtable.add_method(method_a_set, impl_a_set_for_cell);
+    let cell_a_vtable = p.finish_vtable(cell_a_vtable);
+
+    let main = {
+        let mut main = p.declare_function();
+
+        let x = main.declare_local::<Cell>();
+        main.storage_live(x);
+        main.assign(x, const_int(0_u32));
+        let y = main.make_dyn(x, cell_a_vtable, trait_a, <Cell>::FREEZE);
+        main.validate(y, false);
+
+        let unit = main.declare_local::<()>();
+        main.storage_live(unit);
+        main.call(unit, vtable_method_lookup(get_metadata(load(y)), method_a_set), &[by_value(
+            ptr_to_ptr(get_thin_pointer(load(y)), <&Cell>::get_type()),
+        )]);
+        main.assume(eq(load(x), const_int(1_u32)));
+
+        main.exit();
+        p.finish_function(main)
+    };
+
+    let p = p.finish_program(main);
+    assert_stop::<TreeBorrowMem>(p);
+}
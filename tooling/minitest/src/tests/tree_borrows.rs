@@ -0,0 +1,102 @@
+use crate::*;
+
+/// Builds a program where the callee writes to `x` through a raw pointer while it also holds a
+/// shared reference to `x`. If the arguments are validated on function entry, that reference is
+/// protected and the write is UB.
+/// ```rust
+/// fn callee(r: &u32, p: *mut u32) {
+///     unsafe { *p = 1 };
+/// }
+///
+/// let mut x = 0;
+/// callee(&x, &raw mut x);
+/// ```
+fn write_while_protected(validate_args: bool) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let callee = {
+        let mut f = p.declare_function();
+        let _r = f.declare_arg::<&u32>();
+        let ptr = f.declare_arg::<*mut u32>();
+        if validate_args {
+            f.validate_args();
+        }
+        f.assign(deref(load(ptr), <u32>::get_type()), const_int(1u32));
+        f.return_();
+        p.finish_function(f)
+    };
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.assign(x, const_int(0u32));
+    f.call(unit_place(), fn_ptr(callee), &[
+        by_value(addr_of(x, <&u32>::get_type())),
+        by_value(addr_of(x, <*mut u32>::get_type())),
+    ]);
+    f.exit();
+    let f = p.finish_function(f);
+    p.finish_program(f)
+}
+
+/// Without function-entry validation, the reference is never retagged.
+#[test]
+fn write_without_validate_args() {
+    let p = write_while_protected(false);
+    assert_stop::<TreeBorrowMem>(p);
+}
+
+#[test]
+fn write_with_validate_args() {
+    let p = write_while_protected(true);
+    assert_ub::<TreeBorrowMem>(
+        p,
+        "Tree Borrows: a protected pointer with Frozen permission becomes Disabled",
+    );
+}
+
+/// Builds a program that retags a mutable reference to `x`, then reads `x` directly before
+/// writing through the reference.
+/// ```rust
+/// let mut x = 0;
+/// let r = &mut x; // retagged with `fn_entry`
+/// let _val = x;
+/// *r = 1;
+/// ```
+fn read_during_reservation(fn_entry: bool) -> Program {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.assign(x, const_int(0u32));
+    let r = f.declare_local::<&mut u32>();
+    f.storage_live(r);
+    f.assign(r, addr_of(x, <&mut u32>::get_type()));
+    f.validate(r, fn_entry);
+    let val = f.declare_local::<u32>();
+    f.storage_live(val);
+    f.assign(val, load(x));
+    f.assign(deref(load(r), <u32>::get_type()), const_int(1u32));
+    f.exit();
+    let f = p.finish_function(f);
+    p.finish_program(f)
+}
+
+/// A mutable reference may be read by others while it is still reserved, like a two-phase borrow.
+/// MiniRust has no stricter plain mutable retag: all of them are treated like this.
+#[test]
+fn two_phase_retag_allows_foreign_read() {
+    let p = read_during_reservation(false);
+    assert_stop::<TreeBorrowMem>(p);
+}
+
+/// A function-entry retag adds a protector, so the foreign read conflicts the reservation.
+#[test]
+fn fn_entry_retag_conflicts_on_foreign_read() {
+    let p = read_during_reservation(true);
+    assert_ub::<TreeBorrowMem>(
+        p,
+        "Tree Borrows: writing to the child of a protected pointer with Conflicted Reserved permission",
+    );
+}
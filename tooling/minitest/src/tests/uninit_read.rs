@@ -10,3 +10,62 @@ fn uninit_read() {
         "load at type Bool but the data in memory violates the language invariant",
     );
 }
+
+#[test]
+fn deinit_then_read() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let y = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.assign(x, const_int(42u32));
+    f.deinit(x);
+    f.assign(y, load(x));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(
+        p,
+        "load at type Int(IntType { signed: Unsigned, size: Size(4 bytes) }) but the data in memory violates the language invariant",
+    );
+}
+
+#[test]
+fn deinit_then_write_then_read() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    f.storage_live(x);
+    f.assign(x, const_int(42u32));
+    f.deinit(x);
+    f.assign(x, const_int(7u32));
+    f.assume(eq(load(x), const_int(7u32)));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn deinit_then_partial_write() {
+    let mut p = ProgramBuilder::new();
+    let mut f = p.declare_function();
+    let x = f.declare_local::<[u8; 2]>();
+    let y = f.declare_local::<u8>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.assign(x, array(&[const_int(1u8), const_int(2u8)], <u8>::get_type()));
+    f.deinit(x);
+    // Writing only one element leaves the other one uninitialized.
+    f.assign(index(x, const_int(0)), const_int(3u8));
+    f.assume(eq(load(index(x, const_int(0))), const_int(3u8)));
+    f.assign(y, load(index(x, const_int(1))));
+    f.exit();
+    let f = p.finish_function(f);
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(
+        p,
+        "load at type Int(IntType { signed: Unsigned, size: Size(1 bytes) }) but the data in memory violates the language invariant",
+    );
+}
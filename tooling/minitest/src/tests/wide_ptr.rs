@@ -375,3 +375,92 @@ fn compare_trait_obj_ptr() {
     let p = p.finish_program(f);
     assert_stop::<BasicMem>(p);
 }
+
+/// Transmutes between thin and wide pointers have different sizes, so they are UB
+/// rather than a reinterpretation of the bytes.
+#[test]
+fn transmute_thin_to_wide_ub() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let y = f.declare_local::<*const [u32]>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.assign(y, transmute(addr_of(x, <*const u32>::get_type()), <*const [u32]>::get_type()));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "transmute between types of different size");
+}
+
+#[test]
+fn transmute_wide_to_thin_ub() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<[u32; 2]>();
+    let y = f.declare_local::<*const u32>();
+    f.storage_live(x);
+    f.storage_live(y);
+    let wide = construct_wide_pointer(
+        addr_of(x, <*const u32>::get_type()),
+        const_int(2usize),
+        <*const [u32]>::get_type(),
+    );
+    f.assign(y, transmute(wide, <*const u32>::get_type()));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_ub::<BasicMem>(p, "transmute between types of different size");
+}
+
+/// Such transmutes are still well-formed, so they may appear in code that is never executed.
+#[test]
+fn transmute_thin_to_wide_unreachable() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<u32>();
+    let y = f.declare_local::<*const [u32]>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.if_(
+        const_bool(false),
+        |f| {
+            f.assign(y, transmute(addr_of(x, <*const u32>::get_type()), <*const [u32]>::get_type()))
+        },
+        |_| {},
+    );
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
+
+#[test]
+fn transmute_wide_to_wide() {
+    let mut p = ProgramBuilder::new();
+
+    let mut f = p.declare_function();
+    let x = f.declare_local::<[u32; 2]>();
+    let y = f.declare_local::<&[u32]>();
+    f.storage_live(x);
+    f.storage_live(y);
+    f.assign(x, array(&[const_int(1u32), const_int(2u32)], <u32>::get_type()));
+    let wide = construct_wide_pointer(
+        addr_of(x, <*const u32>::get_type()),
+        const_int(2usize),
+        <*const [u32]>::get_type(),
+    );
+    f.assign(y, transmute(wide, <&[u32]>::get_type()));
+    f.assume(eq(get_metadata(load(y)), const_int(2usize)));
+    f.exit();
+    let f = p.finish_function(f);
+
+    let p = p.finish_program(f);
+    assert_stop::<BasicMem>(p);
+}
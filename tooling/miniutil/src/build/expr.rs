@@ -70,6 +70,10 @@ pub fn count_ones(v: ValueExpr) -> ValueExpr {
     ValueExpr::UnOp { operator: UnOp::Int(IntUnOp::CountOnes), operand: GcCow::new(v) }
 }
 
+pub fn trailing_zeros(v: ValueExpr) -> ValueExpr {
+    ValueExpr::UnOp { operator: UnOp::Int(IntUnOp::TrailingZeros), operand: GcCow::new(v) }
+}
+
 #[track_caller]
 pub fn int_cast<T: TypeConv>(v: ValueExpr) -> ValueExpr {
     let Type::Int(t) = T::get_type() else {
@@ -135,6 +139,15 @@ pub fn construct_wide_pointer(ptr: ValueExpr, meta: ValueExpr, ptr_ty: Type) ->
     }
 }
 
+/// Creates a `&[T]` pointing to the first `len` elements of the array stored at `arr`.
+pub fn array_to_slice<T: TypeConv>(arr: PlaceExpr, len: impl Into<Int>) -> ValueExpr {
+    construct_wide_pointer(
+        addr_of(arr, <&T>::get_type()),
+        const_int_typed::<usize>(len.into()),
+        <&[T]>::get_type(),
+    )
+}
+
 fn int_binop(op: IntBinOp, l: ValueExpr, r: ValueExpr) -> ValueExpr {
     ValueExpr::BinOp { operator: BinOp::Int(op), left: GcCow::new(l), right: GcCow::new(r) }
 }
@@ -184,9 +197,57 @@ pub fn bit_and(l: ValueExpr, r: ValueExpr) -> ValueExpr {
 pub fn bit_or(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop(IntBinOp::BitOr, l, r)
 }
+pub fn bit_or_disjoint(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop(IntBinOp::BitOrDisjoint, l, r)
+}
 pub fn bit_xor(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop(IntBinOp::BitXor, l, r)
 }
+pub fn int_min(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop(IntBinOp::Min, l, r)
+}
+pub fn int_max(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop(IntBinOp::Max, l, r)
+}
+
+/// Computes `l * r + carry + add` as a `(low, high)` tuple of two unsigned `T`.
+///
+/// The result cannot overflow a type of twice the width, so the computation is done in `Wide`,
+/// which has to be an unsigned type twice as large as `T`.
+#[track_caller]
+pub fn carrying_mul_add<T: TypeConv, Wide: TypeConv>(
+    l: ValueExpr,
+    r: ValueExpr,
+    carry: ValueExpr,
+    add: ValueExpr,
+) -> ValueExpr {
+    let (Type::Int(int_ty), Type::Int(wide_ty)) = (T::get_type(), Wide::get_type()) else {
+        panic!("carrying_mul_add requires integer types!");
+    };
+    assert!(int_ty.signed == Unsigned && wide_ty.signed == Unsigned);
+    assert_eq!(
+        wide_ty.size.bytes(),
+        int_ty.size.bytes() * 2,
+        "`Wide` must be twice as large as `T`"
+    );
+
+    let wide = add_unchecked(
+        add_unchecked(
+            mul_unchecked(int_cast::<Wide>(l), int_cast::<Wide>(r)),
+            int_cast::<Wide>(carry),
+        ),
+        int_cast::<Wide>(add),
+    );
+    let low = int_cast::<T>(wide);
+    let high = int_cast::<T>(shr(wide, const_int_typed::<u32>(int_ty.size.bits())));
+
+    let ty = tuple_ty(
+        &[(size(0), T::get_type()), (T::get_size(), T::get_type())],
+        wide_ty.size,
+        T::get_align(),
+    );
+    tuple(&[low, high], ty)
+}
 
 fn int_overflow(op: IntBinOpWithOverflow, l: ValueExpr, r: ValueExpr) -> ValueExpr {
     ValueExpr::BinOp {
@@ -310,6 +371,18 @@ pub fn const_vtable(vtable_name: VTableName, trait_name: TraitName) -> ValueExpr
     )
 }
 
+/// Turn the thin pointer `data_ptr` into a pointer of type `ptr_ty` to a trait object,
+/// using `vtable` as its metadata.
+pub fn unsize_to_dyn(data_ptr: ValueExpr, vtable: VTableName, ptr_ty: Type) -> ValueExpr {
+    let Type::Ptr(ptr) = ptr_ty else {
+        panic!("unsize_to_dyn requires Type::Ptr argument!");
+    };
+    let PointerMetaKind::VTablePointer(trait_name) = ptr.meta_kind() else {
+        panic!("unsize_to_dyn requires a pointer to a trait object!");
+    };
+    construct_wide_pointer(data_ptr, const_vtable(vtable, trait_name), ptr_ty)
+}
+
 pub fn vtable_method_lookup(operand: ValueExpr, method: TraitMethodName) -> ValueExpr {
     ValueExpr::UnOp { operator: UnOp::VTableMethodLookup(method), operand: GcCow::new(operand) }
 }
@@ -331,6 +404,15 @@ pub fn downcast(root: PlaceExpr, discriminant: impl Into<Int>) -> PlaceExpr {
     PlaceExpr::Downcast { root: GcCow::new(root), discriminant: discriminant.into() }
 }
 
+/// A field of the enum variant at the specified index, i.e. a `Field` of a `Downcast`.
+pub fn variant_field(
+    root: PlaceExpr,
+    discriminant: impl Into<Int>,
+    field_idx: impl Into<Int>,
+) -> PlaceExpr {
+    field(downcast(root, discriminant), field_idx)
+}
+
 /// A place suited for 1-aligned zero-sized accesses.
 pub fn unit_place() -> PlaceExpr {
     let ptr =
@@ -144,6 +144,7 @@ pub struct FunctionBuilder {
 
     start: BbName,
     ret: Option<LocalName>,
+    calling_convention: CallingConvention,
 
     cur_block: Option<CurBlock>,
 
@@ -160,6 +161,7 @@ impl FunctionBuilder {
             args: Default::default(),
             start: BbName(Name::from_internal(0)),
             ret: None,
+            calling_convention: CallingConvention::C,
             cur_block: None,
             next_block: 0,
             next_local: 0,
@@ -212,7 +214,7 @@ impl FunctionBuilder {
             locals: self.locals,
             args: self.args,
             ret: self.ret.unwrap(),
-            calling_convention: CallingConvention::C,
+            calling_convention: self.calling_convention,
             blocks: self.blocks,
             start: self.start,
         }
@@ -222,6 +224,11 @@ impl FunctionBuilder {
         self.name
     }
 
+    /// Set the calling convention of this function. Defaults to `CallingConvention::C`.
+    pub fn set_calling_convention(&mut self, conv: CallingConvention) {
+        self.calling_convention = conv;
+    }
+
     fn fresh_local_name(&mut self) -> LocalName {
         let name = LocalName(Name::from_internal(self.next_local));
         self.next_local += 1;
@@ -284,6 +291,12 @@ impl VTableBuilder {
         self.methods.insert(index, func);
     }
 
+    pub fn add_methods(&mut self, methods: &[(TraitMethodName, FnName)]) {
+        for &(index, func) in methods {
+            self.add_method(index, func);
+        }
+    }
+
     #[track_caller]
     fn finish_vtable(self) -> VTable {
         VTable {
@@ -318,6 +331,11 @@ impl TraitBuilder {
         name
     }
 
+    /// Declares `N` methods at once, e.g. `let [foo, bar] = t.declare_methods();`.
+    pub fn declare_methods<const N: usize>(&mut self) -> [TraitMethodName; N] {
+        std::array::from_fn(|_| self.declare_method())
+    }
+
     #[track_caller]
     fn finish_trait(self) -> Set<TraitMethodName> {
         self.method_names
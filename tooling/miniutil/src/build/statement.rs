@@ -5,6 +5,22 @@ impl FunctionBuilder {
         self.cur_block().statements.push(Statement::Assign { destination, source });
     }
 
+    /// Declare a local holding a `&dyn Trait` pointing to `place`, using `vtable` as its metadata.
+    /// `freeze` says whether the type behind the vtable is `Freeze`, see `ref_ty_for_trait_object`.
+    pub fn make_dyn(
+        &mut self,
+        place: PlaceExpr,
+        vtable: VTableName,
+        trait_name: TraitName,
+        freeze: bool,
+    ) -> PlaceExpr {
+        let ty = ref_ty_for_trait_object(trait_name, freeze);
+        let local = self.declare_local_with_ty(ty);
+        self.storage_live(local);
+        self.assign(local, unsize_to_dyn(addr_of(place, raw_void_ptr_ty()), vtable, ty));
+        local
+    }
+
     pub fn place_mention(&mut self, place: PlaceExpr) {
         self.cur_block().statements.push(Statement::PlaceMention(place));
     }
@@ -15,8 +31,41 @@ impl FunctionBuilder {
             .push(Statement::SetDiscriminant { destination, value: value.into() });
     }
 
+    /// Initialize the enum at `destination` to the variant with the given discriminant:
+    /// assign each of its fields in order, then set the discriminant.
+    pub fn init_variant(
+        &mut self,
+        destination: PlaceExpr,
+        discriminant: impl Into<Int>,
+        fields: &[ValueExpr],
+    ) {
+        let discriminant = discriminant.into();
+        for (idx, value) in fields.iter().enumerate() {
+            self.assign(variant_field(destination, discriminant, idx), *value);
+        }
+        self.set_discriminant(destination, discriminant);
+    }
+
+    /// De-initialize `place`, i.e., make all of its bytes uninitialized.
+    pub fn deinit(&mut self, place: PlaceExpr) {
+        self.cur_block().statements.push(Statement::Deinit { place });
+    }
+
+    /// Validate (and thereby retag) `place`. See [`validate`] for how MIR retags map to this.
     pub fn validate(&mut self, place: PlaceExpr, fn_entry: bool) {
-        self.cur_block().statements.push(Statement::Validate { place, fn_entry });
+        self.cur_block().statements.push(validate(place, fn_entry));
+    }
+
+    /// Validate all arguments declared so far with `fn_entry: true`, like the prelude that
+    /// minimize emits at the top of every function. This is what makes retagging add protectors.
+    /// Must be called in the start block, after all arguments have been declared.
+    #[track_caller]
+    pub fn validate_args(&mut self) {
+        let start = self.start;
+        assert_eq!(self.cur_block().name, start, "arguments must be validated in the start block");
+        for arg in self.args {
+            self.validate(local_by_name(arg), true);
+        }
     }
 
     pub fn storage_live(&mut self, local: PlaceExpr) {
@@ -42,6 +91,15 @@ pub fn set_discriminant(destination: PlaceExpr, value: impl Into<Int>) -> Statem
     Statement::SetDiscriminant { destination, value: value.into() }
 }
 
+pub fn deinit(place: PlaceExpr) -> Statement {
+    Statement::Deinit { place }
+}
+
+/// Validate (and thereby retag) `place`.
+///
+/// This is what MIR `Retag` statements become: `fn_entry` is set for `RetagKind::FnEntry`, which
+/// adds protectors. MiniRust cannot express the other retag kinds: Tree Borrows treats every
+/// mutable reference like a two-phase borrow, and raw pointers are never retagged.
 pub fn validate(place: PlaceExpr, fn_entry: bool) -> Statement {
     Statement::Validate { place, fn_entry }
 }
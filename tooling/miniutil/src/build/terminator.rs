@@ -1,5 +1,8 @@
 use crate::build::*;
 
+/// A branch of a switch built with the `FunctionBuilder`.
+type SwitchBranch<'a> = &'a dyn Fn(&mut FunctionBuilder);
+
 impl FunctionBuilder {
     #[track_caller]
     fn finish_block(&mut self, terminator: Terminator) {
@@ -13,6 +16,10 @@ impl FunctionBuilder {
         self.finish_block(exit());
     }
 
+    pub fn exit_code(&mut self, code: ValueExpr) {
+        self.finish_block(exit_code(code));
+    }
+
     pub fn unreachable(&mut self) {
         self.finish_block(Terminator::Unreachable);
     }
@@ -29,6 +36,10 @@ impl FunctionBuilder {
         self.finish_block(panic());
     }
 
+    pub fn panic_msg(&mut self, msg: ValueExpr) {
+        self.finish_block(panic_msg(msg));
+    }
+
     /// Call a function that does not return.
     pub fn call_noret(&mut self, ret: PlaceExpr, f: ValueExpr, args: &[ArgumentExpr]) {
         self.finish_block(Terminator::Call {
@@ -42,10 +53,22 @@ impl FunctionBuilder {
 
     // terminators with exactly 1 following block
     pub fn call(&mut self, ret: PlaceExpr, f: ValueExpr, args: &[ArgumentExpr]) {
+        // FIXME do not hard-code the C calling convention
+        self.call_with_conv(CallingConvention::C, ret, f, args)
+    }
+
+    /// Call a function, using the calling convention `conv` at the call site.
+    pub fn call_with_conv(
+        &mut self,
+        conv: CallingConvention,
+        ret: PlaceExpr,
+        f: ValueExpr,
+        args: &[ArgumentExpr],
+    ) {
         let next_block = self.declare_block();
         self.finish_block(Terminator::Call {
             callee: f,
-            calling_convention: CallingConvention::C, // FIXME do not hard-code the C calling convention
+            calling_convention: conv,
             arguments: args.iter().copied().collect(),
             ret,
             next_block: Some(next_block),
@@ -72,30 +95,125 @@ impl FunctionBuilder {
         self.set_cur_block(next_block);
     }
 
+    /// Assume that `compute_size` and `compute_align` of the sized type `ty` are `size` and `align`,
+    /// and that they agree with `Type::layout`, which is what e.g. vtables are built from.
+    pub fn assume_layout(&mut self, ty: Type, size: Size, align: Align) {
+        let layout = ty.layout::<DefaultTarget>();
+        assert_eq!(layout.expect_size("type must be sized"), size);
+        assert_eq!(layout.expect_align("type must be sized"), align);
+        self.assume(eq(compute_size(ty, unit()), const_int_typed::<usize>(size.bytes())));
+        self.assume(eq(compute_align(ty, unit()), const_int_typed::<usize>(align.bytes())));
+    }
+
+    /// Read the discriminant of the enum at `place` and continue.
+    /// MiniRust has no sentinel for invalid discriminants: if `place` does not hold a
+    /// valid one, reading it is UB, so this can be used to check enum layouts.
+    pub fn assert_valid_discriminant(&mut self, place: PlaceExpr) {
+        let next_block = self.declare_block();
+        self.finish_block(Terminator::Switch {
+            value: get_discriminant(place),
+            cases: Map::new(),
+            fallback: next_block,
+        });
+        self.set_cur_block(next_block);
+    }
+
     pub fn print(&mut self, arg: ValueExpr) {
         let next_block = self.declare_block();
         self.finish_block(print(arg, bbname_into_u32(next_block)));
         self.set_cur_block(next_block);
     }
 
+    pub fn print_all(&mut self, args: &[ValueExpr]) {
+        let next_block = self.declare_block();
+        self.finish_block(print_all(args, bbname_into_u32(next_block)));
+        self.set_cur_block(next_block);
+    }
+
     pub fn eprint(&mut self, arg: ValueExpr) {
         let next_block = self.declare_block();
         self.finish_block(eprint(arg, bbname_into_u32(next_block)));
         self.set_cur_block(next_block)
     }
 
+    pub fn eprint_all(&mut self, args: &[ValueExpr]) {
+        let next_block = self.declare_block();
+        self.finish_block(eprint_all(args, bbname_into_u32(next_block)));
+        self.set_cur_block(next_block)
+    }
+
+    pub fn write(&mut self, fd: ValueExpr, bytes: ValueExpr) {
+        let next_block = self.declare_block();
+        self.finish_block(write(fd, bytes, bbname_into_u32(next_block)));
+        self.set_cur_block(next_block)
+    }
+
     pub fn allocate(&mut self, size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr) {
         let next_block = self.declare_block();
         self.finish_block(allocate(size, align, ret_place, bbname_into_u32(next_block)));
         self.set_cur_block(next_block)
     }
 
+    pub fn allocate_zeroed(&mut self, size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr) {
+        let next_block = self.declare_block();
+        self.finish_block(allocate_zeroed(size, align, ret_place, bbname_into_u32(next_block)));
+        self.set_cur_block(next_block)
+    }
+
     pub fn deallocate(&mut self, ptr: ValueExpr, size: ValueExpr, align: ValueExpr) {
         let next_block = self.declare_block();
         self.finish_block(deallocate(ptr, size, align, bbname_into_u32(next_block)));
         self.set_cur_block(next_block)
     }
 
+    pub fn reallocate(
+        &mut self,
+        ptr: ValueExpr,
+        old_size: ValueExpr,
+        align: ValueExpr,
+        new_size: ValueExpr,
+        ret_place: PlaceExpr,
+    ) {
+        let next_block = self.declare_block();
+        self.finish_block(reallocate(
+            ptr,
+            old_size,
+            align,
+            new_size,
+            ret_place,
+            bbname_into_u32(next_block),
+        ));
+        self.set_cur_block(next_block)
+    }
+
+    /// Allocate heap memory for a `T`, initialize it with `value`,
+    /// and return a fresh local of type `Box<T>` pointing to it.
+    pub fn box_new<T: TypeConv>(&mut self, value: ValueExpr) -> PlaceExpr {
+        let ptr = self.declare_local::<*mut T>();
+        self.storage_live(ptr);
+        self.allocate(
+            const_int_typed::<usize>(T::get_size().bytes()),
+            const_int_typed::<usize>(T::get_align().bytes()),
+            ptr,
+        );
+        let boxed = self.declare_local::<Box<T>>();
+        self.storage_live(boxed);
+        self.assign(boxed, transmute(load(ptr), <Box<T>>::get_type()));
+        self.storage_dead(ptr);
+        self.assign(deref(load(boxed), T::get_type()), value);
+        boxed
+    }
+
+    /// Deallocate the memory of a `Box<T>` created by `box_new`, and end the storage of its local.
+    pub fn box_drop<T: TypeConv>(&mut self, boxed: PlaceExpr) {
+        self.deallocate(
+            transmute(load(boxed), <*mut T>::get_type()),
+            const_int_typed::<usize>(T::get_size().bytes()),
+            const_int_typed::<usize>(T::get_align().bytes()),
+        );
+        self.storage_dead(boxed);
+    }
+
     pub fn spawn(&mut self, f: FnName, data_ptr: ValueExpr, ret: PlaceExpr) {
         let next_block = self.declare_block();
         self.finish_block(spawn(fn_ptr(f), data_ptr, ret, bbname_into_u32(next_block)));
@@ -114,6 +232,12 @@ impl FunctionBuilder {
         self.set_cur_block(next_block)
     }
 
+    pub fn swap(&mut self, left_ptr: ValueExpr, right_ptr: ValueExpr) {
+        let next_block = self.declare_block();
+        self.finish_block(swap(left_ptr, right_ptr, bbname_into_u32(next_block)));
+        self.set_cur_block(next_block)
+    }
+
     pub fn atomic_store(&mut self, ptr: ValueExpr, src: ValueExpr) {
         let next_block = self.declare_block();
         self.finish_block(atomic_store(ptr, src, bbname_into_u32(next_block)));
@@ -239,6 +363,21 @@ impl FunctionBuilder {
         }
     }
 
+    /// Like `switch_int`, but the cases are `char`s.
+    /// MiniRust has no `char` type, so `value` must be the scalar value of the char as a `u32`.
+    pub fn switch_char<G>(
+        &mut self,
+        value: ValueExpr,
+        cases: &[(char, SwitchBranch<'_>)],
+        fallback: G,
+    ) where
+        G: Fn(&mut Self),
+    {
+        let cases: Vec<(u32, SwitchBranch<'_>)> =
+            cases.iter().map(|(case, branch)| (u32::from(*case), *branch)).collect();
+        self.switch_int(value, &cases, fallback);
+    }
+
     pub fn while_<F: Fn(&mut Self)>(&mut self, condition: ValueExpr, body: F) {
         // goto new block such that condition sits alone in dedicated block
         let cond = self.declare_block();
@@ -317,6 +456,26 @@ pub fn print(arg: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+/// Print each of `args` on its own line.
+pub fn print_all(args: &[ValueExpr], next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::PrintStdout,
+        arguments: args.iter().copied().collect(),
+        ret: unit_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Write the bytes of the `&[u8]` `bytes` to the file descriptor `fd` (1 for stdout, 2 for stderr).
+pub fn write(fd: ValueExpr, bytes: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Write,
+        arguments: list![fd, bytes],
+        ret: unit_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn eprint(arg: ValueExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::PrintStderr,
@@ -326,6 +485,16 @@ pub fn eprint(arg: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+/// Print each of `args` on its own line to stderr.
+pub fn eprint_all(args: &[ValueExpr], next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::PrintStderr,
+        arguments: args.iter().copied().collect(),
+        ret: unit_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn allocate(size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Allocate,
@@ -335,6 +504,20 @@ pub fn allocate(size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u
     }
 }
 
+pub fn allocate_zeroed(
+    size: ValueExpr,
+    align: ValueExpr,
+    ret_place: PlaceExpr,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::AllocateZeroed,
+        arguments: list![size, align],
+        ret: ret_place,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn deallocate(ptr: ValueExpr, size: ValueExpr, align: ValueExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Deallocate,
@@ -344,6 +527,22 @@ pub fn deallocate(ptr: ValueExpr, size: ValueExpr, align: ValueExpr, next: u32)
     }
 }
 
+pub fn reallocate(
+    ptr: ValueExpr,
+    old_size: ValueExpr,
+    align: ValueExpr,
+    new_size: ValueExpr,
+    ret_place: PlaceExpr,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Reallocate,
+        arguments: list![ptr, old_size, align, new_size],
+        ret: ret_place,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn exit() -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Exit,
@@ -353,6 +552,15 @@ pub fn exit() -> Terminator {
     }
 }
 
+pub fn exit_code(code: ValueExpr) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Exit,
+        arguments: list![code],
+        ret: unit_place(),
+        next_block: None,
+    }
+}
+
 pub fn panic() -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Panic,
@@ -362,6 +570,16 @@ pub fn panic() -> Terminator {
     }
 }
 
+/// Abort with the message given by the `&[u8]` or `&str` `msg`.
+pub fn panic_msg(msg: ValueExpr) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Panic,
+        arguments: list![msg],
+        ret: unit_place(),
+        next_block: None,
+    }
+}
+
 pub fn return_() -> Terminator {
     Terminator::Return
 }
@@ -393,6 +611,15 @@ pub fn raw_eq(ret: PlaceExpr, left_ptr: ValueExpr, right_ptr: ValueExpr, next: u
     }
 }
 
+pub fn swap(left_ptr: ValueExpr, right_ptr: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Swap,
+        arguments: list!(left_ptr, right_ptr),
+        ret: unit_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn atomic_store(ptr: ValueExpr, src: ValueExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::AtomicStore,
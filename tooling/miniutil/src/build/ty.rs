@@ -89,6 +89,20 @@ pub fn trait_object_ty(trait_name: TraitName) -> Type {
     Type::TraitObject(trait_name)
 }
 
+/// Create a shared reference type to `dyn Trait`.
+///
+/// A trait object does not know the type behind its vtable, so whether the pointee is `Freeze`
+/// has to be passed explicitly, e.g. as `<T>::FREEZE` for the type the vtable was declared for.
+/// Pass `false` if that type contains an `UnsafeCell`: shared references to it are then not retagged.
+pub fn ref_ty_for_trait_object(trait_name: TraitName, freeze: bool) -> Type {
+    ref_ty(PointeeInfo {
+        layout: trait_object_ty(trait_name).layout::<DefaultTarget>(),
+        inhabited: true,
+        freeze,
+        unpin: true,
+    })
+}
+
 pub fn enum_variant(ty: Type, tagger: &[(Offset, (IntType, Int))]) -> Variant {
     Variant { ty, tagger: tagger.iter().copied().collect() }
 }
@@ -95,16 +95,43 @@ impl<T: TypeConv + ?Sized> TypeConv for &mut T {
     }
 }
 
+impl<T: TypeConv + ?Sized> TypeConv for Box<T> {
+    fn get_type() -> Type {
+        box_ty(PointeeInfo {
+            layout: T::get_layout(),
+            inhabited: true,
+            freeze: T::FREEZE,
+            unpin: T::UNPIN,
+        })
+    }
+}
+
 impl<T: TypeConv, const N: usize> TypeConv for [T; N] {
     fn get_type() -> Type {
         array_ty(T::get_type(), N)
     }
+
+    const FREEZE: bool = T::FREEZE;
+    const UNPIN: bool = T::UNPIN;
 }
 
 impl<T: TypeConv> TypeConv for [T] {
     fn get_type() -> Type {
         slice_ty(T::get_type())
     }
+
+    const FREEZE: bool = T::FREEZE;
+    const UNPIN: bool = T::UNPIN;
+}
+
+/// MiniRust has no notion of `UnsafeCell`; it has the layout of its contents,
+/// but references to it are not `Freeze`.
+impl<T: TypeConv> TypeConv for std::cell::UnsafeCell<T> {
+    fn get_type() -> Type {
+        T::get_type()
+    }
+
+    const FREEZE: bool = false;
 }
 
 impl TypeConv for () {
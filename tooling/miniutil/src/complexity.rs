@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Size metrics of the types used by a program.
+/// Used to check that the size of translated types stays linear in the size of the source types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// This does not measure the cost of the well-formedness check, which is not instrumented:
+/// tests should run the check on the same program to make sure it stays tractable.
+pub struct Complexity {
+    /// The number of structurally distinct `Type` nodes reachable from the program.
+    pub distinct_types: usize,
+}
+
+/// Compute the complexity of all types of locals in `prog`.
+pub fn program_complexity(prog: &Program) -> Complexity {
+    let mut walker = TypeWalker::default();
+    for (_, f) in prog.functions.iter() {
+        for (_, ty) in f.locals.iter() {
+            walker.visit(ty);
+        }
+    }
+    walker.complexity()
+}
+
+/// Compute the complexity of a single type.
+pub fn type_complexity(ty: Type) -> Complexity {
+    let mut walker = TypeWalker::default();
+    walker.visit(ty);
+    walker.complexity()
+}
+
+#[derive(Default)]
+struct TypeWalker {
+    /// We cannot use a `HashSet` here: the hash of a `Map` depends on its iteration order,
+    /// which differs between structurally equal maps.
+    seen: Vec<Type>,
+}
+
+impl TypeWalker {
+    fn complexity(&self) -> Complexity {
+        Complexity { distinct_types: self.seen.len() }
+    }
+
+    fn visit(&mut self, ty: Type) {
+        if self.seen.contains(&ty) {
+            return;
+        }
+        self.seen.push(ty);
+
+        match ty {
+            Type::Int(_) | Type::Bool | Type::Ptr(_) | Type::TraitObject(_) => {}
+            Type::Tuple { sized_fields, unsized_field, .. } => {
+                for (_, field) in sized_fields.iter() {
+                    self.visit(field);
+                }
+                if let Some(unsized_ty) = unsized_field.extract() {
+                    self.visit(unsized_ty);
+                }
+            }
+            Type::Array { elem, .. } | Type::Slice { elem } => self.visit(elem.extract()),
+            Type::Union { fields, .. } =>
+                for (_, field) in fields.iter() {
+                    self.visit(field);
+                },
+            Type::Enum { variants, .. } =>
+                for (_, variant) in variants.iter() {
+                    self.visit(variant.ty);
+                },
+        }
+    }
+}
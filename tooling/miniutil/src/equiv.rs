@@ -0,0 +1,396 @@
+use crate::*;
+
+use crate::fmt::fmt_program;
+
+use std::collections::{HashMap, VecDeque};
+
+/// The difference between two programs that are not equivalent.
+/// Shows both programs after renaming, so that equivalent parts print identically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    pub left: String,
+    pub right: String,
+}
+
+/// Check whether two programs are the same up to a consistent renaming of functions, basic blocks
+/// and locals. Globals, vtables and traits have to agree exactly.
+///
+/// Names are matched up in the order in which they are first reached from the start function,
+/// globals and vtables. Unreachable functions and blocks as well as unused locals are matched up in
+/// the order of their names instead, so renaming those such that their order changes makes
+/// equivalent programs compare as not equivalent.
+pub fn programs_equivalent(left: &Program, right: &Program) -> Result<(), Diff> {
+    let left = canonicalize(*left);
+    let right = canonicalize(*right);
+    if left == right {
+        return Ok(());
+    }
+    let (mut left_str, mut right_str) = (fmt_program(left), fmt_program(right));
+    if left_str == right_str {
+        // The difference is in something the pretty-printer does not show.
+        (left_str, right_str) = (format!("{left:#?}"), format!("{right:#?}"));
+    }
+    Err(Diff { left: left_str, right: right_str })
+}
+
+/// The number of unchanged lines shown around each change.
+const CONTEXT: usize = 2;
+
+/// The largest table we build to compute a minimal diff of the changed lines.
+const MAX_LCS_CELLS: usize = 1 << 20;
+
+impl std::fmt::Display for Diff {
+    /// Prints a line diff: lines only in `left` start with `-`, lines only in `right` with `+`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let left: Vec<&str> = self.left.lines().collect();
+        let right: Vec<&str> = self.right.lines().collect();
+
+        // Lines before the first and after the last change are unchanged.
+        let prefix = left.iter().zip(&right).take_while(|(l, r)| l == r).count();
+        let suffix = left[prefix..]
+            .iter()
+            .rev()
+            .zip(right[prefix..].iter().rev())
+            .take_while(|(l, r)| l == r)
+            .count();
+        let (changed_left, changed_right) =
+            (&left[prefix..left.len() - suffix], &right[prefix..right.len() - suffix]);
+
+        let mut lines: Vec<(char, &str)> = left[..prefix].iter().map(|line| (' ', *line)).collect();
+        if changed_left.len() * changed_right.len() <= MAX_LCS_CELLS {
+            lines.extend(lcs_diff(changed_left, changed_right));
+        } else {
+            // Too large to compute a minimal diff: show the whole changed range.
+            lines.extend(changed_left.iter().map(|line| ('-', *line)));
+            lines.extend(changed_right.iter().map(|line| ('+', *line)));
+        }
+        lines.extend(left[left.len() - suffix..].iter().map(|line| (' ', *line)));
+
+        // Only show unchanged lines close to a change.
+        let near_change = |idx: usize| {
+            let lo = idx.saturating_sub(CONTEXT);
+            let hi = (idx + CONTEXT + 1).min(lines.len());
+            lines[lo..hi].iter().any(|(kind, _)| *kind != ' ')
+        };
+        let mut skipped = false;
+        for (idx, (kind, line)) in lines.iter().enumerate() {
+            if near_change(idx) {
+                writeln!(f, "{kind} {line}")?;
+                skipped = false;
+            } else if !skipped {
+                writeln!(f, "  ...")?;
+                skipped = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A minimal line diff of `left` and `right`, computed from their longest common subsequence.
+/// This needs `left.len() * right.len()` space.
+fn lcs_diff<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<(char, &'a str)> {
+    // `lcs[i][j]` is the length of the longest common subsequence of `left[i..]` and `right[j..]`.
+    let mut lcs = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lcs[i][j] = if left[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            lines.push((' ', left[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == right.len() || (i < left.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', left[i]));
+            i += 1;
+        } else {
+            lines.push(('+', right[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Rename all functions, basic blocks and locals in the order in which they are first reached,
+/// starting at the start function. Equivalent programs become equal this way.
+fn canonicalize(prog: Program) -> Program {
+    let mut fns = Renaming::default();
+    fns.rename(prog.start);
+    // Functions can also be reached from globals and vtables.
+    for (_, global) in sorted(prog.globals, |GlobalName(name)| name) {
+        for (_, reloc) in global.relocations {
+            if let RelocationTarget::Fn(f) = reloc {
+                fns.rename(f);
+            }
+        }
+    }
+    for (_, vtable) in sorted(prog.vtables, |VTableName(name)| name) {
+        for (_, f) in sorted(vtable.methods, |TraitMethodName(name)| name) {
+            fns.rename(f);
+        }
+    }
+
+    let mut functions = Map::new();
+    let mut done = 0;
+    loop {
+        // Unreachable functions are matched up in the order of their names.
+        if done == fns.order.len() {
+            let Some((f, _)) =
+                sorted(prog.functions, |FnName(name)| name).find(|(f, _)| !fns.map.contains_key(f))
+            else {
+                break;
+            };
+            fns.rename(f);
+        }
+        let old = fns.order[done];
+        done += 1;
+        // Ill-formed programs can refer to functions that do not exist.
+        let Some(function) = prog.functions.get(old) else { continue };
+        let function = FnCanonicalizer::new(&mut fns).function(function);
+        functions.insert(fns.map[&old], function);
+    }
+
+    let globals = prog
+        .globals
+        .iter()
+        .map(|(name, global)| {
+            let relocations = global.relocations.map(|(offset, reloc)| {
+                let reloc = match reloc {
+                    RelocationTarget::Fn(f) => RelocationTarget::Fn(fns.map[&f]),
+                    reloc => reloc,
+                };
+                (offset, reloc)
+            });
+            (name, Global { relocations, ..global })
+        })
+        .collect();
+    let vtables = prog
+        .vtables
+        .iter()
+        .map(|(name, vtable)| {
+            let methods = vtable.methods.iter().map(|(m, f)| (m, fns.map[&f])).collect();
+            (name, VTable { methods, ..vtable })
+        })
+        .collect();
+
+    Program { functions, start: fns.map[&prog.start], globals, vtables, ..prog }
+}
+
+/// The entries of `map`, ordered by `key`.
+fn sorted<K: Obj, V: Obj, O: Ord>(
+    map: Map<K, V>,
+    key: impl Fn(K) -> O,
+) -> impl Iterator<Item = (K, V)> {
+    let mut entries: Vec<(K, V)> = map.iter().collect();
+    entries.sort_by_key(|(k, _)| key(*k));
+    entries.into_iter()
+}
+
+/// Hands out fresh names in the order in which the old names are first seen.
+struct Renaming<N> {
+    map: HashMap<N, N>,
+    order: Vec<N>,
+}
+
+impl<N> Default for Renaming<N> {
+    fn default() -> Self {
+        Renaming { map: HashMap::new(), order: Vec::new() }
+    }
+}
+
+trait NameLike: Copy + Eq + std::hash::Hash {
+    fn from_index(idx: u32) -> Self;
+}
+
+impl NameLike for FnName {
+    fn from_index(idx: u32) -> Self {
+        FnName(Name::from_internal(idx))
+    }
+}
+
+impl NameLike for BbName {
+    fn from_index(idx: u32) -> Self {
+        BbName(Name::from_internal(idx))
+    }
+}
+
+impl NameLike for LocalName {
+    fn from_index(idx: u32) -> Self {
+        LocalName(Name::from_internal(idx))
+    }
+}
+
+impl<N: NameLike> Renaming<N> {
+    fn rename(&mut self, old: N) -> N {
+        if let Some(new) = self.map.get(&old) {
+            return *new;
+        }
+        let new = N::from_index(self.order.len() as u32);
+        self.map.insert(old, new);
+        self.order.push(old);
+        new
+    }
+}
+
+/// Renames the names inside a single function.
+struct FnCanonicalizer<'a> {
+    fns: &'a mut Renaming<FnName>,
+    blocks: Renaming<BbName>,
+    locals: Renaming<LocalName>,
+}
+
+impl<'a> FnCanonicalizer<'a> {
+    fn new(fns: &'a mut Renaming<FnName>) -> Self {
+        FnCanonicalizer { fns, blocks: Renaming::default(), locals: Renaming::default() }
+    }
+
+    fn function(mut self, f: Function) -> Function {
+        let ret = self.locals.rename(f.ret);
+        let args = f.args.map(|arg| self.locals.rename(arg));
+        let start = self.blocks.rename(f.start);
+
+        let mut queue = VecDeque::from([f.start]);
+        let mut blocks = Map::new();
+        loop {
+            let Some(old) = queue.pop_front().or_else(|| {
+                // Unreachable blocks are matched up in the order of their names.
+                sorted(f.blocks, |BbName(name)| name)
+                    .map(|(bb, _)| bb)
+                    .find(|bb| !self.blocks.map.contains_key(bb))
+            }) else {
+                break;
+            };
+            let new = self.blocks.rename(old);
+            // Ill-formed programs can jump to blocks that do not exist.
+            let Some(block) = f.blocks.get(old) else { continue };
+            let seen = self.blocks.order.len();
+            let block = self.block(block);
+            blocks.insert(new, block);
+            queue.extend(self.blocks.order[seen..].iter().copied());
+        }
+
+        // Unused locals are matched up in the order of their names.
+        for (local, _) in sorted(f.locals, |LocalName(name)| name) {
+            self.locals.rename(local);
+        }
+        let locals = f.locals.iter().map(|(local, ty)| (self.locals.map[&local], ty)).collect();
+
+        Function { locals, args, ret, blocks, start, ..f }
+    }
+
+    fn block(&mut self, block: BasicBlock) -> BasicBlock {
+        let statements = block.statements.map(|stmt| self.statement(stmt));
+        let terminator = self.terminator(block.terminator);
+        BasicBlock { statements, terminator }
+    }
+
+    fn statement(&mut self, stmt: Statement) -> Statement {
+        match stmt {
+            Statement::Assign { destination, source } =>
+                Statement::Assign {
+                    destination: self.place(destination),
+                    source: self.value(source),
+                },
+            Statement::PlaceMention(place) => Statement::PlaceMention(self.place(place)),
+            Statement::SetDiscriminant { destination, value } =>
+                Statement::SetDiscriminant { destination: self.place(destination), value },
+            Statement::Validate { place, fn_entry } =>
+                Statement::Validate { place: self.place(place), fn_entry },
+            Statement::Deinit { place } => Statement::Deinit { place: self.place(place) },
+            Statement::StorageLive(local) => Statement::StorageLive(self.locals.rename(local)),
+            Statement::StorageDead(local) => Statement::StorageDead(self.locals.rename(local)),
+        }
+    }
+
+    fn terminator(&mut self, terminator: Terminator) -> Terminator {
+        match terminator {
+            Terminator::Goto(bb) => Terminator::Goto(self.blocks.rename(bb)),
+            Terminator::Switch { value, cases, fallback } => {
+                let value = self.value(value);
+                let cases = sorted(cases, |case| case)
+                    .map(|(case, bb)| (case, self.blocks.rename(bb)))
+                    .collect();
+                Terminator::Switch { value, cases, fallback: self.blocks.rename(fallback) }
+            }
+            Terminator::Unreachable => Terminator::Unreachable,
+            Terminator::Intrinsic { intrinsic, arguments, ret, next_block } =>
+                Terminator::Intrinsic {
+                    intrinsic,
+                    arguments: arguments.map(|arg| self.value(arg)),
+                    ret: self.place(ret),
+                    next_block: next_block.map(|bb| self.blocks.rename(bb)),
+                },
+            Terminator::Call { callee, calling_convention, arguments, ret, next_block } =>
+                Terminator::Call {
+                    callee: self.value(callee),
+                    calling_convention,
+                    arguments: arguments.map(|arg| self.argument(arg)),
+                    ret: self.place(ret),
+                    next_block: next_block.map(|bb| self.blocks.rename(bb)),
+                },
+            Terminator::Return => Terminator::Return,
+        }
+    }
+
+    fn argument(&mut self, arg: ArgumentExpr) -> ArgumentExpr {
+        match arg {
+            ArgumentExpr::ByValue(value) => ArgumentExpr::ByValue(self.value(value)),
+            ArgumentExpr::InPlace(place) => ArgumentExpr::InPlace(self.place(place)),
+        }
+    }
+
+    fn value(&mut self, value: ValueExpr) -> ValueExpr {
+        match value {
+            ValueExpr::Constant(Constant::FnPointer(f), ty) =>
+                ValueExpr::Constant(Constant::FnPointer(self.fns.rename(f)), ty),
+            ValueExpr::Constant(..) => value,
+            ValueExpr::Tuple(values, ty) => ValueExpr::Tuple(values.map(|v| self.value(v)), ty),
+            ValueExpr::Union { field, expr, union_ty } =>
+                ValueExpr::Union { field, expr: GcCow::new(self.value(expr.extract())), union_ty },
+            ValueExpr::Variant { discriminant, data, enum_ty } =>
+                ValueExpr::Variant {
+                    discriminant,
+                    data: GcCow::new(self.value(data.extract())),
+                    enum_ty,
+                },
+            ValueExpr::GetDiscriminant { place } =>
+                ValueExpr::GetDiscriminant { place: GcCow::new(self.place(place.extract())) },
+            ValueExpr::Load { source } =>
+                ValueExpr::Load { source: GcCow::new(self.place(source.extract())) },
+            ValueExpr::AddrOf { target, ptr_ty } =>
+                ValueExpr::AddrOf { target: GcCow::new(self.place(target.extract())), ptr_ty },
+            ValueExpr::UnOp { operator, operand } =>
+                ValueExpr::UnOp { operator, operand: GcCow::new(self.value(operand.extract())) },
+            ValueExpr::BinOp { operator, left, right } =>
+                ValueExpr::BinOp {
+                    operator,
+                    left: GcCow::new(self.value(left.extract())),
+                    right: GcCow::new(self.value(right.extract())),
+                },
+        }
+    }
+
+    fn place(&mut self, place: PlaceExpr) -> PlaceExpr {
+        match place {
+            PlaceExpr::Local(local) => PlaceExpr::Local(self.locals.rename(local)),
+            PlaceExpr::Deref { operand, ty } =>
+                PlaceExpr::Deref { operand: GcCow::new(self.value(operand.extract())), ty },
+            PlaceExpr::Field { root, field } =>
+                PlaceExpr::Field { root: GcCow::new(self.place(root.extract())), field },
+            PlaceExpr::Index { root, index } =>
+                PlaceExpr::Index {
+                    root: GcCow::new(self.place(root.extract())),
+                    index: GcCow::new(self.value(index.extract())),
+                },
+            PlaceExpr::Downcast { root, discriminant } =>
+                PlaceExpr::Downcast { root: GcCow::new(self.place(root.extract())), discriminant },
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+/// Format the control-flow graphs of all functions in `prog` as a Graphviz `digraph`.
+///
+/// Every function becomes a cluster, and every basic block a node labeled with its statements.
+pub fn fmt_cfg(prog: Program) -> String {
+    let mut comptypes: Vec<CompType> = Vec::new();
+
+    let mut fns: Vec<(FnName, Function)> = prog.functions.iter().collect();
+
+    // Functions are formatted in the order given by their name.
+    fns.sort_by_key(|(FnName(name), _fn)| *name);
+
+    let mut out = String::from("digraph program {\n  node [shape=box, fontname=monospace];\n");
+    for (fn_name, f) in fns {
+        out += &fmt_function_cfg(fn_name, f, &mut comptypes);
+    }
+    out += "}\n";
+    out
+}
+
+fn fmt_function_cfg(fn_name: FnName, f: Function, comptypes: &mut Vec<CompType>) -> String {
+    let fn_name = fmt_fn_name(fn_name);
+    let mut out = format!("  subgraph cluster_{fn_name} {{\n    label = \"{fn_name}\";\n");
+
+    let mut blocks: Vec<(BbName, BasicBlock)> = f.blocks.iter().collect();
+
+    // Basic blocks are formatted in the order of their names.
+    blocks.sort_by_key(|(BbName(name), _block)| *name);
+
+    for (bb_name, bb) in blocks {
+        let node = format!("{fn_name}_{}", fmt_bb_name(bb_name));
+        let label = escape_label(&fmt_bb(bb_name, bb, f.start == bb_name, comptypes));
+        out += &format!("    {node} [label=\"{label}\"];\n");
+        for succ in successors(bb.terminator) {
+            out += &format!("    {node} -> {fn_name}_{};\n", fmt_bb_name(succ));
+        }
+    }
+    out += "  }\n";
+    out
+}
+
+// Escapes a block for use as a Graphviz label, with all lines left-aligned.
+fn escape_label(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out += "\\l",
+            c => out.push(c),
+        }
+    }
+    out
+}
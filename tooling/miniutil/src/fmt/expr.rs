@@ -145,6 +145,8 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
                 UnOp::Int(IntUnOp::BitNot) => FmtExpr::NonAtomic(format!("!({operand}")),
                 UnOp::Int(IntUnOp::CountOnes) =>
                     FmtExpr::NonAtomic(format!("count_ones({operand}")),
+                UnOp::Int(IntUnOp::TrailingZeros) =>
+                    FmtExpr::NonAtomic(format!("trailing_zeros({operand}")),
                 UnOp::Cast(CastOp::IntToInt(int_ty)) => {
                     let int_ty = fmt_int_type(int_ty);
                     FmtExpr::Atomic(format!("int2int<{int_ty}>({operand})"))
@@ -189,9 +191,12 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
                 AddUnchecked => return FmtExpr::Atomic(format!("AddUnchecked({l}, {r})")),
                 SubUnchecked => return FmtExpr::Atomic(format!("SubUnchecked({l}, {r})")),
                 MulUnchecked => return FmtExpr::Atomic(format!("MulUnchecked({l}, {r})")),
+                BitOrDisjoint => return FmtExpr::Atomic(format!("BitOrDisjoint({l}, {r})")),
                 DivExact => return FmtExpr::Atomic(format!("DivExact({l}, {r})")),
                 ShlUnchecked => return FmtExpr::Atomic(format!("ShlUnchecked({l}, {r})")),
                 ShrUnchecked => return FmtExpr::Atomic(format!("ShrUnchecked({l}, {r})")),
+                Min => return FmtExpr::Atomic(format!("Min({l}, {r})")),
+                Max => return FmtExpr::Atomic(format!("Max({l}, {r})")),
             };
 
             FmtExpr::NonAtomic(format!("{l} {int_op} {r}"))
@@ -66,7 +66,12 @@ fn fmt_function(
     out
 }
 
-fn fmt_bb(bb_name: BbName, bb: BasicBlock, start: bool, comptypes: &mut Vec<CompType>) -> String {
+pub(super) fn fmt_bb(
+    bb_name: BbName,
+    bb: BasicBlock,
+    start: bool,
+    comptypes: &mut Vec<CompType>,
+) -> String {
     let name = bb_name.0.get_internal();
 
     let mut out = if start { format!("  start bb{name}:\n") } else { format!("  bb{name}:\n") };
@@ -154,8 +159,11 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
         }
         Terminator::Switch { value, cases, fallback } => {
             let branch_expr = fmt_value_expr(value, comptypes).to_string();
+            // The cases are formatted in the order of their values.
+            let mut cases: Vec<(Int, BbName)> = cases.iter().collect();
+            cases.sort_by_key(|(constant, _)| *constant);
             let mut case_strs = cases
-                .iter()
+                .into_iter()
                 .map(|(constant, successor)| format!("{}: {}", constant, fmt_bb_name(successor)))
                 .collect::<Vec<String>>();
             case_strs.push(format!("otherwise: {}", fmt_bb_name(fallback)));
@@ -196,11 +204,15 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
                 IntrinsicOp::Panic => "panic",
                 IntrinsicOp::PrintStdout => "print",
                 IntrinsicOp::PrintStderr => "eprint",
+                IntrinsicOp::Write => "write",
                 IntrinsicOp::Allocate => "allocate",
+                IntrinsicOp::AllocateZeroed => "allocate_zeroed",
                 IntrinsicOp::Deallocate => "deallocate",
+                IntrinsicOp::Reallocate => "reallocate",
                 IntrinsicOp::Spawn => "spawn",
                 IntrinsicOp::Join => "join",
                 IntrinsicOp::RawEq => "raw_eq",
+                IntrinsicOp::Swap => "swap",
                 IntrinsicOp::AtomicStore => "atomic_store",
                 IntrinsicOp::AtomicLoad => "atomic_load",
                 IntrinsicOp::AtomicCompareExchange => "atomic_compare_exchange",
@@ -227,7 +239,7 @@ fn fmt_fetch(binop: IntBinOp) -> &'static str {
     }
 }
 
-fn fmt_bb_name(bb: BbName) -> String {
+pub(super) fn fmt_bb_name(bb: BbName) -> String {
     let id = bb.0.get_internal();
     format!("bb{id}")
 }
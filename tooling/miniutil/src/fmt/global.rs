@@ -36,7 +36,11 @@ fn fmt_global(gname: GlobalName, global: Global) -> String {
     );
     for (i, rel) in global.relocations {
         let i = i.bytes();
-        let rel_str = fmt_relocation(rel).to_string();
+        let rel_str = match rel {
+            RelocationTarget::Global(rel) => fmt_relocation(rel).to_string(),
+            RelocationTarget::Fn(fn_name) => fmt_fn_name(fn_name),
+            RelocationTarget::VTable(vtable_name) => fmt_vtable_name(vtable_name),
+        };
         out += &format!("  at byte {i}: {rel_str},\n");
     }
     out += "}\n\n";
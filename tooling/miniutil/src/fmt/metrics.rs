@@ -0,0 +1,67 @@
+use crate::*;
+
+/// Control-flow metrics of a single function.
+/// Useful to spot pathological translations in large programs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Metrics {
+    /// The number of basic blocks.
+    pub block_count: usize,
+    /// The number of control-flow edges between basic blocks.
+    /// Several switch cases jumping to the same block count as a single edge.
+    pub edge_count: usize,
+    /// The largest number of distinct successors of any basic block.
+    pub max_fan_out: usize,
+}
+
+impl Metrics {
+    /// The cyclomatic complexity `E - N + 2` of the control-flow graph.
+    pub fn cyclomatic_complexity(&self) -> usize {
+        (self.edge_count + 2).saturating_sub(self.block_count)
+    }
+}
+
+// `Metrics` only contains plain integers, so it points to nothing.
+impl GcCompat for Metrics {
+    fn points_to(&self, _buffer: &mut std::collections::HashSet<usize>) {}
+}
+
+/// Compute the control-flow metrics of every function in `prog`.
+pub fn function_metrics(prog: Program) -> Map<FnName, Metrics> {
+    prog.functions.iter().map(|(name, f)| (name, metrics_of(f))).collect()
+}
+
+fn metrics_of(f: Function) -> Metrics {
+    let mut edge_count = 0;
+    let mut max_fan_out = 0;
+    for (_, block) in f.blocks.iter() {
+        let fan_out = successors(block.terminator).len();
+        edge_count += fan_out;
+        max_fan_out = max_fan_out.max(fan_out);
+    }
+    Metrics { block_count: f.blocks.len().try_to_usize().unwrap(), edge_count, max_fan_out }
+}
+
+/// The distinct blocks that control can flow to from `terminator`.
+pub(super) fn successors(terminator: Terminator) -> Vec<BbName> {
+    let mut succs = Vec::new();
+    let mut add = |bb: BbName| {
+        if !succs.contains(&bb) {
+            succs.push(bb);
+        }
+    };
+    match terminator {
+        Terminator::Goto(bb) => add(bb),
+        Terminator::Switch { cases, fallback, .. } => {
+            for (_, bb) in cases.iter() {
+                add(bb);
+            }
+            add(fallback);
+        }
+        Terminator::Intrinsic { next_block, .. } | Terminator::Call { next_block, .. } =>
+            if let Some(bb) = next_block {
+                add(bb);
+            },
+        Terminator::Unreachable | Terminator::Return => {}
+    }
+    succs
+}
@@ -15,12 +15,54 @@ use global::*;
 mod vtables;
 use vtables::*;
 
+mod metrics;
+pub use metrics::*;
+
+mod snapshot;
+pub use snapshot::*;
+
+mod cfg;
+pub use cfg::*;
+
 // Print a program to stdout.
 pub fn dump_program(prog: Program) {
     let s = fmt_program(prog);
     println!("{s}");
 }
 
+/// Format where in a program an ill-formed construct was found, e.g. `f0, bb2, statement 1`.
+pub fn fmt_ill_formed_location(location: IllFormedLocation) -> String {
+    let at = match location.statement {
+        Some(idx) => format!("statement {idx}"),
+        None => "terminator".to_string(),
+    };
+    format!("{}, {}, {at}", fmt_fn_name(location.func), fmt_bb_name(location.block))
+}
+
+/// Format the two accesses of a data race, e.g.
+/// `non-atomic store of 4 bytes at address 16 by thread 0 races with atomic load of 4 bytes at address 16 by thread 1`.
+pub fn fmt_data_race(first: RacingAccess, second: RacingAccess) -> String {
+    format!("{} races with {}", fmt_racing_access(second), fmt_racing_access(first))
+}
+
+fn fmt_racing_access(racing: RacingAccess) -> String {
+    let access = racing.access;
+    let atomicity = match access.atomicity {
+        Atomicity::Atomic => "atomic",
+        Atomicity::None => "non-atomic",
+    };
+    let ty = match access.ty {
+        AccessType::Store => "store",
+        AccessType::Load => "load",
+    };
+    format!(
+        "{atomicity} {ty} of {} bytes at address {} by thread {}",
+        access.len.bytes(),
+        access.addr,
+        racing.thread
+    )
+}
+
 // Format a program into a string.
 pub fn fmt_program(prog: Program) -> String {
     let mut comptypes: Vec<CompType> = Vec::new();
@@ -0,0 +1,99 @@
+use super::*;
+use crate::mock_write::MockWrite;
+
+/// Run `prog` for (at most) `steps` steps and render the state of the machine:
+/// the program counter and locals of every stack frame, and the live allocations.
+///
+/// Pointers are rendered relative to the allocation they point into, so the output does not
+/// depend on the (non-deterministic) addresses chosen by the memory model.
+/// Anything printed by the program is discarded.
+pub fn debug_snapshot<M: Memory>(prog: Program, steps: usize) -> String {
+    let out = DynWrite::new(MockWrite::new());
+    let err = DynWrite::new(MockWrite::new());
+    let mut machine = match Machine::<M>::new(prog, out, err).get_internal() {
+        Ok(machine) => machine,
+        Err(info) => return format!("machine could not be created: {info:?}\n"),
+    };
+
+    let mut out = String::new();
+    let mut executed = 0;
+    while executed < steps {
+        if let Err(info) = machine.step().get_internal() {
+            out += &format!("terminated after {executed} steps: {info:?}\n");
+            break;
+        }
+        executed += 1;
+    }
+    if executed == steps {
+        out += &format!("after {steps} steps\n");
+    }
+
+    out + &fmt_machine(machine)
+}
+
+/// Run `prog` until control first enters the function `fn_name` and render the machine state.
+///
+/// The output has the same format as [`debug_snapshot`]. At that point, the callee's frame is the innermost one and its arguments have been initialized.
+/// If the program terminates (or cannot even start) without entering the function, returns how it terminated.
+pub fn run_until_fn<M: Memory>(prog: Program, fn_name: FnName) -> Result<String, TerminationInfo> {
+    assert!(prog.functions.contains_key(fn_name), "run_until_fn: function does not exist");
+    let out = DynWrite::new(MockWrite::new());
+    let err = DynWrite::new(MockWrite::new());
+    let mut machine = Machine::<M>::new(prog, out, err).get_internal()?;
+
+    let mut executed = 0;
+    // Initially, no thread has a stack, so this also detects the start function.
+    let mut stacks = List::new();
+    loop {
+        let new_stacks = machine.thread_stacks();
+        // A thread entered `fn_name` if its stack grew (or it was just spawned) and `fn_name` is on top.
+        let entered = new_stacks.iter().enumerate().any(|(i, stack)| {
+            let old_len = stacks.get(Int::from(i)).map_or(Int::ZERO, |s: List<FnName>| s.len());
+            stack.len() > old_len && stack.last() == Some(fn_name)
+        });
+        if entered {
+            break;
+        }
+        stacks = new_stacks;
+
+        machine.step().get_internal()?;
+        executed += 1;
+    }
+
+    let header = format!("entered {} after {executed} steps\n", fmt_fn_name(fn_name));
+    Ok(header + &fmt_machine(machine))
+}
+
+/// Render the stack frames of all threads and the live allocations.
+fn fmt_machine<M: Memory>(mut machine: Machine<M>) -> String {
+    let mut out = String::new();
+    for (i, (state, frames)) in machine.inspect_threads().iter().enumerate() {
+        out += &format!("thread {i} ({state:?}):\n");
+        for frame in frames.iter() {
+            out += &fmt_frame(&machine, frame);
+        }
+    }
+    out += "allocations:\n";
+    for (i, alloc) in machine.live_allocations().iter().enumerate() {
+        out += &format!("  alloc{i}: {:?}, {} bytes\n", alloc.kind, alloc.size.bytes());
+    }
+    out
+}
+
+/// Render a stack frame. Values are rendered like the print intrinsics do.
+fn fmt_frame<M: Memory>(machine: &Machine<M>, frame: FrameInfo<M>) -> String {
+    let fn_name = fmt_fn_name(frame.fn_name);
+    let block = fmt_bb_name(frame.next_block);
+    let mut out = format!("  {fn_name}, {block}, statement {}\n", frame.next_stmt);
+
+    let mut locals: Vec<_> = frame.locals.iter().collect();
+    locals.sort_by_key(|(name, _)| name.0.get_internal());
+    for (name, val) in locals {
+        let val = match val {
+            Some(val) => machine.fmt_value(val).get_internal(),
+            None => String::from("<invalid>"),
+        };
+        out += &format!("    {} = {val}\n", fmt_local_name(name));
+    }
+    out
+}
@@ -180,7 +180,10 @@ fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) ->
         Type::Enum { variants, discriminant_ty, .. } => {
             let discr = fmt_int_type(discriminant_ty);
             s += &format!("  Discriminant: {discr}\n");
-            variants.iter().for_each(|(discriminant, v)| {
+            // The variants are formatted in the order of their discriminants.
+            let mut variants: Vec<(Int, Variant)> = variants.iter().collect();
+            variants.sort_by_key(|(discriminant, _)| *discriminant);
+            variants.into_iter().for_each(|(discriminant, v)| {
                 let typ = fmt_type(v.ty, comptypes).to_string();
                 s += &format!("  Variant {discriminant}: {typ}\n");
             });
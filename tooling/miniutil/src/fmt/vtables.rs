@@ -27,7 +27,10 @@ fn fmt_vtable(vname: VTableName, vtable: VTable) -> String {
     out += &format!("  size = {},\n", vtable.size.bytes());
     out += &format!("  align = {},\n", vtable.align.bytes());
 
-    for (meth, impel) in vtable.methods {
+    // The methods are formatted in the order of their names.
+    let mut methods: Vec<(TraitMethodName, FnName)> = vtable.methods.iter().collect();
+    methods.sort_by_key(|(TraitMethodName(name), _)| *name);
+    for (meth, impel) in methods {
         out += &format!(
             "  {meth}() = {f},\n",
             meth = fmt_trait_method_name(meth),
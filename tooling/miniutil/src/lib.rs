@@ -18,6 +18,8 @@ pub use std::result::Result;
 pub use std::string::String;
 
 pub mod build;
+pub mod complexity;
+pub mod equiv;
 pub mod fmt;
 pub mod mock_write;
 pub mod run;
@@ -14,6 +14,11 @@ impl MockWrite {
         Default::default()
     }
 
+    /// Get the entire output as a single String, exactly as it was written.
+    pub fn into_string(self) -> String {
+        from_utf8(&self.buffer.borrow()).unwrap().to_string()
+    }
+
     /// Get all output lines as Strings.
     pub fn into_strings(self) -> Vec<String> {
         let slice = self.buffer.borrow();
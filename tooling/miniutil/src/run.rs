@@ -1,51 +1,159 @@
+use std::collections::HashMap;
+
 use crate::{mock_write::MockWrite, *};
 
 /// Run the program and return its TerminationInfo.
 /// Stdout/stderr are just forwarded to the host.
 pub fn run_program<M: Memory>(prog: Program) -> TerminationInfo {
-    let out = std::io::stdout();
-    let err = std::io::stderr();
+    run_to_end::<M>(prog, std::io::stdout(), |_| {})
+}
+
+/// Run the program for at most `max_steps` steps and return its TerminationInfo,
+/// or `None` if it did not terminate within that many steps.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_bounded<M: Memory>(prog: Program, max_steps: usize) -> Option<TerminationInfo> {
+    match run_with::<M>(prog, std::io::stdout(), Some(max_steps), |_| {}) {
+        Ok((info, _machine)) => info,
+        Err(info) => Some(info),
+    }
+}
+
+/// Run the program with a limit on live heap memory and return its TerminationInfo.
+///
+/// Allocating more than `max_live_bytes` on the heap yields `TerminationInfo::AllocFailure`.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_max_live_bytes<M: Memory>(
+    prog: Program,
+    max_live_bytes: Size,
+) -> TerminationInfo {
+    run_to_end::<M>(prog, std::io::stdout(), |machine| machine.set_max_live_bytes(max_live_bytes))
+}
+
+/// Run the program with a limit on the stack depth of each thread and return its TerminationInfo.
+///
+/// Calls that would exceed `max_stack_depth` frames yield `TerminationInfo::StackOverflow`.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_max_stack_depth<M: Memory>(
+    prog: Program,
+    max_stack_depth: usize,
+) -> TerminationInfo {
+    run_to_end::<M>(prog, std::io::stdout(), |machine| {
+        machine.set_max_stack_depth(Int::from(max_stack_depth))
+    })
+}
 
-    let res: Result<!, TerminationInfo> = run::<M>(prog, out, err);
-    match res {
-        Ok(never) => never,
-        Err(t) => t,
+/// Run the program for at most `max_steps` steps and return the allocations live at that point.
+///
+/// If the program terminated within that many steps, its TerminationInfo is returned as well.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_memory<M: Memory>(
+    prog: Program,
+    max_steps: usize,
+) -> (Option<TerminationInfo>, Vec<AllocationInfo>) {
+    match run_with::<M>(prog, std::io::stdout(), Some(max_steps), |_| {}) {
+        Ok((info, machine)) => (info, machine.live_allocations().iter().collect()),
+        Err(info) => (Some(info), Vec::new()),
     }
 }
 
 /// Run the program and return stdout as a `Vec<String>`  or a termination info
 /// if it did not terminate correctly. Stderr is just forwarded to the host.
 pub fn get_stdout<M: Memory>(prog: Program) -> Result<Vec<String>, TerminationInfo> {
+    match run_program_with_stdout::<M>(prog) {
+        (TerminationInfo::MachineStop(_), stdout) => Ok(stdout),
+        (info, _) => Err(info),
+    }
+}
+
+/// Run the program and return stdout as a single `String`, exactly as it was written,
+/// or a termination info if it did not terminate correctly. Stderr is just forwarded to the host.
+pub fn get_stdout_raw<M: Memory>(prog: Program) -> Result<String, TerminationInfo> {
     let out = MockWrite::new();
-    let err = std::io::stderr();
+    match run_to_end::<M>(prog, out.clone(), |_| {}) {
+        TerminationInfo::MachineStop(_) => Ok(out.into_string()),
+        info => Err(info),
+    }
+}
 
-    let res = run::<M>(prog, out.clone(), err);
-    match res {
-        Ok(never) => never,
-        Err(TerminationInfo::MachineStop) => Ok(out.into_strings()),
+/// Run the program and return the arguments of each `PrintStdout` call, or a termination info
+/// if it did not terminate correctly. Stdout is discarded, stderr is just forwarded to the host.
+pub fn get_printed_values<M: Memory>(prog: Program) -> Result<Vec<Vec<Value<M>>>, TerminationInfo> {
+    match run_with::<M>(prog, MockWrite::new(), None, |machine| machine.record_printed_values()) {
+        Ok((Some(TerminationInfo::MachineStop(_)), machine)) => {
+            let printed_values = machine.printed_values().unwrap();
+            Ok(printed_values.iter().map(|args| args.iter().collect()).collect())
+        }
+        Ok((info, _machine)) => Err(info.unwrap()),
         Err(info) => Err(info),
     }
 }
 
-/// Run the program to completion using the given writers for stdout/stderr.
+/// Run the program and return its TerminationInfo together with stdout as a `Vec<String>`,
+/// no matter how it terminated. Stderr is just forwarded to the host.
+pub fn run_program_with_stdout<M: Memory>(prog: Program) -> (TerminationInfo, Vec<String>) {
+    let out = MockWrite::new();
+    let info = run_to_end::<M>(prog, out.clone(), |_| {});
+    (info, out.into_strings())
+}
+
+/// Run the program `attempts` many times and count how often each distinct outcome occurs.
 ///
-/// We fix `BasicMemory` as a memory for now.
-fn run<M: Memory>(
+/// An outcome is the TerminationInfo together with the raw bytes written to stdout.
+/// The runs cannot be seeded, since the non-determinism in `libspecr::pick` always draws from
+/// the thread-local RNG; so this takes a number of attempts rather than a list of seeds.
+/// Stderr is just forwarded to the host.
+pub fn run_program_outcomes<M: Memory>(
+    prog: Program,
+    attempts: usize,
+) -> HashMap<(TerminationInfo, Vec<u8>), usize> {
+    let mut outcomes = HashMap::new();
+    for _ in 0..attempts {
+        let out = MockWrite::new();
+        let info = run_to_end::<M>(prog, out.clone(), |_| {});
+        *outcomes.entry((info, out.into_string().into_bytes())).or_insert(0) += 1;
+    }
+    outcomes
+}
+
+/// Like `run_with`, but runs the program until it terminates and only returns how it terminated.
+fn run_to_end<M: Memory>(
     prog: Program,
     stdout: impl GcWrite,
-    stderr: impl GcWrite,
-) -> Result<!, TerminationInfo> {
-    let res: NdResult<!> = try {
-        let mut machine = Machine::<M>::new(prog, DynWrite::new(stdout), DynWrite::new(stderr))?;
+    configure: impl FnOnce(&mut Machine<M>),
+) -> TerminationInfo {
+    match run_with::<M>(prog, stdout, None, configure) {
+        Ok((info, _machine)) => info.expect("the program stopped running without terminating"),
+        Err(info) => info,
+    }
+}
 
-        loop {
-            machine.step()?;
+/// Create a machine for the program that writes to `stdout`, apply `configure` to it, and run it
+/// for at most `max_steps` steps (or until it terminates, if `max_steps` is `None`).
+///
+/// Returns how the program terminated (or `None` if it is still running) together with the machine
+/// in its final state. If the machine cannot even be created, only its TerminationInfo is returned.
+/// Stderr is just forwarded to the host.
+fn run_with<M: Memory>(
+    prog: Program,
+    stdout: impl GcWrite,
+    max_steps: Option<usize>,
+    configure: impl FnOnce(&mut Machine<M>),
+) -> Result<(Option<TerminationInfo>, Machine<M>), TerminationInfo> {
+    let stderr = std::io::stderr();
+    let mut machine =
+        Machine::<M>::new(prog, DynWrite::new(stdout), DynWrite::new(stderr)).get_internal()?;
+    configure(&mut machine);
 
-            // Drops everything not reachable from `machine`.
-            mark_and_sweep(&machine);
+    let mut steps = 0;
+    while max_steps.is_none_or(|max_steps| steps < max_steps) {
+        if let Err(info) = machine.step().get_internal() {
+            return Ok((Some(info), machine));
         }
-    };
+        steps += 1;
+
+        // Drops everything not reachable from `machine`.
+        mark_and_sweep(&machine);
+    }
 
-    // Extract the TerminationInfo from the `NdResult<!>`.
-    res.get_internal()
+    Ok((None, machine))
 }